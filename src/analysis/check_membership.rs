@@ -0,0 +1,160 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use graph_process_manager_core::process::filter::{AbstractStepFilter, GenericFiltersManager};
+use graph_process_manager_core::process::manager::GenericProcessManager;
+use graph_process_manager_core::queue::priorities::GenericProcessPriorities;
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use crate::core::semantics::multitrace::{multitrace_from_global_trace, MultiTrace};
+use crate::core::semantics::trace_action::TraceAction;
+use crate::core::syntax::lang_traits::eliminate_lf::eliminable::LifelineEliminable;
+use crate::core::syntax::lang_traits::involve::involves::InvolvesLifelines;
+use crate::core::syntax::interaction::Interaction;
+
+use super::process::conf::TraceAnalysisConfig;
+use super::process::context::TraceAnalysisContextAndParameterization;
+use super::process::filter::{TraceAnalysisNodePreFilter, TraceAnalysisStepFilter};
+use super::process::node::TraceAnalysisNode;
+use super::process::priorities::TraceAnalysisPriorities;
+use super::verdict::AnalysisVerdict;
+
+
+
+/**
+ * Parameterization of the exploration performed when analyzing a (multi-)trace.
+ * - strategy : the order in which the search space is explored (BFS or DFS)
+ * - max_memoized_nodes : cap on the number of nodes kept for memoization (None disables memoization)
+ * - max_node_number : if set, the exploration is truncated after that many nodes and may yield Inconc
+ * - lifeline_removal : if set, the lifelines of exhausted components are removed on the fly, which may yield WeakPass
+ **/
+pub struct TraceAnalysisParameterization {
+    pub strategy : QueueSearchStrategy,
+    pub max_memoized_nodes : Option<usize>,
+    pub max_node_number : Option<u32>,
+    pub lifeline_removal : bool
+}
+
+impl TraceAnalysisParameterization {
+    pub fn new(
+        strategy : QueueSearchStrategy,
+        max_memoized_nodes : Option<usize>,
+        max_node_number : Option<u32>,
+        lifeline_removal : bool
+    ) -> Self {
+        Self { strategy, max_memoized_nodes, max_node_number, lifeline_removal }
+    }
+}
+
+impl Default for TraceAnalysisParameterization {
+    fn default() -> Self {
+        Self::new(QueueSearchStrategy::DFS, Some(10_000), None, false)
+    }
+}
+
+
+
+/**
+ * Checks whether a global trace is accepted by an interaction.
+ **/
+pub fn check_trace_membership(
+    interaction : &Interaction,
+    trace : &[TraceAction]
+) -> AnalysisVerdict {
+    check_trace_membership_with_param(interaction, trace, TraceAnalysisParameterization::default())
+}
+
+pub fn check_trace_membership_with_param(
+    interaction : &Interaction,
+    trace : &[TraceAction],
+    param : TraceAnalysisParameterization
+) -> AnalysisVerdict {
+    // a global trace is a multi-trace with a single component collected on all lifelines
+    // hence no lifeline of the interaction is eliminated as unobserved
+    let mut lf_ids : BTreeSet<usize> = interaction.lifelines_that_may_be_involved();
+    lf_ids.extend(trace.iter().map(|act| act.lf_id));
+    check_multitrace_membership_with_param(
+        interaction,
+        &multitrace_from_global_trace(lf_ids, trace),
+        param
+    )
+}
+
+
+
+/**
+ * Checks whether a multi-trace is accepted by an interaction
+ * i.e. whether there exists a global trace of the interaction which projections
+ * onto the components' lifelines are exactly the components.
+ * Lifelines that are not observed by any component are eliminated from the interaction beforehand.
+ **/
+pub fn check_multitrace_membership(
+    interaction : &Interaction,
+    multitrace : &MultiTrace
+) -> AnalysisVerdict {
+    check_multitrace_membership_with_param(interaction, multitrace, TraceAnalysisParameterization::default())
+}
+
+pub fn check_multitrace_membership_with_param(
+    interaction : &Interaction,
+    multitrace : &MultiTrace,
+    param : TraceAnalysisParameterization
+) -> AnalysisVerdict {
+    let context_and_param = TraceAnalysisContextAndParameterization::new(
+        multitrace,
+        param.max_memoized_nodes,
+        param.lifeline_removal
+    );
+
+    let mut step_filters : Vec<Box<dyn AbstractStepFilter<TraceAnalysisConfig>>> = vec![];
+    if let Some(max_node_number) = param.max_node_number {
+        step_filters.push(Box::new(TraceAnalysisStepFilter::MaxNodeNumber(max_node_number)));
+    }
+
+    // partial observation : the interaction is projected onto the observed lifelines
+    let observed_lfs : BTreeSet<usize> = multitrace.keys().flatten().cloned().collect();
+    let unobserved_lfs : BTreeSet<usize> = interaction.lifelines_that_may_be_involved()
+        .difference(&observed_lfs).cloned().collect();
+    let observed_interaction = if unobserved_lfs.is_empty() {
+        interaction.clone()
+    } else {
+        interaction.eliminate_lifelines(&unobserved_lfs)
+    };
+
+    let initial_node = TraceAnalysisNode::new(observed_interaction, vec![0;multitrace.len()], false);
+
+    let mut manager : GenericProcessManager<TraceAnalysisConfig> = GenericProcessManager::new(
+        context_and_param,
+        param.strategy,
+        GenericProcessPriorities::new(TraceAnalysisPriorities{},false),
+        GenericFiltersManager::new(
+            vec![Box::new(TraceAnalysisNodePreFilter{})],
+            vec![],
+            step_filters
+        ),
+        vec![],
+        // memoization is handled within the global state so that it can be capped
+        false,
+        initial_node
+    );
+
+    let _ = manager.start_process();
+
+    manager.global_state.verdict
+}
@@ -0,0 +1,44 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use graph_process_manager_core::process::config::AbstractProcessConfiguration;
+
+use super::context::TraceAnalysisContextAndParameterization;
+use super::filtration::TraceAnalysisFiltrationResult;
+use super::handler::TraceAnalysisHandler;
+use super::node::TraceAnalysisNode;
+use super::priorities::TraceAnalysisPriorities;
+use super::state::TraceAnalysisGlobalState;
+use super::step::TraceAnalysisStepKind;
+
+
+
+pub struct TraceAnalysisConfig {}
+
+impl AbstractProcessConfiguration for TraceAnalysisConfig {
+    type ContextAndParameterization = TraceAnalysisContextAndParameterization;
+    // ***
+    type AlgorithmOperationHandler = TraceAnalysisHandler;
+    // ***
+    type DomainSpecificNode = TraceAnalysisNode;
+    type DomainSpecificStep = TraceAnalysisStepKind;
+    type Priorities = TraceAnalysisPriorities;
+    // ***
+    type MutablePersistentState = TraceAnalysisGlobalState;
+    // ***
+    type FiltrationResult = TraceAnalysisFiltrationResult;
+}
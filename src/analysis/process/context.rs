@@ -0,0 +1,58 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use crate::core::semantics::multitrace::MultiTrace;
+use crate::core::semantics::trace_action::TraceAction;
+
+
+
+pub struct TraceAnalysisContextAndParameterization {
+    // the components of the multi-trace to analyze, each with the lifelines on which it is collected
+    pub components : Vec<(BTreeSet<usize>,Vec<TraceAction>)>,
+    // maximum number of nodes that are kept for memoization
+    // None means that memoization is disabled
+    pub max_memoized_nodes : Option<usize>,
+    // whether or not the lifelines of exhausted components are removed on the fly
+    pub lifeline_removal : bool
+}
+
+impl TraceAnalysisContextAndParameterization {
+    pub fn new(multitrace : &MultiTrace, max_memoized_nodes : Option<usize>, lifeline_removal : bool) -> Self {
+        let components = multitrace.iter().map(|(lfs,comp)| (lfs.clone(),comp.clone())).collect();
+        Self { components, max_memoized_nodes, lifeline_removal }
+    }
+
+    pub fn is_fully_consumed(&self, consumed : &[usize]) -> bool {
+        self.components.iter().zip(consumed).all(|((_,comp),got)| *got >= comp.len())
+    }
+
+    pub fn get_lifelines_of_exhausted_components(&self, consumed : &[usize]) -> BTreeSet<usize> {
+        let mut lfs = BTreeSet::new();
+        for ((comp_lfs,comp),got) in self.components.iter().zip(consumed) {
+            if *got >= comp.len() {
+                lfs.extend(comp_lfs.iter().cloned());
+            }
+        }
+        lfs
+    }
+
+    pub fn get_component_of_lifeline(&self, lf_id : usize) -> Option<usize> {
+        self.components.iter().position(|(lfs,_)| lfs.contains(&lf_id))
+    }
+}
@@ -0,0 +1,80 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use graph_process_manager_core::process::filter::{AbstractNodePreFilter, AbstractStepFilter};
+
+use super::{conf::TraceAnalysisConfig, context::TraceAnalysisContextAndParameterization, filtration::TraceAnalysisFiltrationResult, node::TraceAnalysisNode, state::TraceAnalysisGlobalState, step::TraceAnalysisStepKind};
+
+
+
+pub enum TraceAnalysisStepFilter {
+    MaxNodeNumber(u32)
+}
+
+impl AbstractStepFilter<TraceAnalysisConfig> for TraceAnalysisStepFilter {
+    fn apply_filter(
+        &self,
+        _context_and_param : &TraceAnalysisContextAndParameterization,
+        global_state : &TraceAnalysisGlobalState,
+        _parent_node : &TraceAnalysisNode,
+        _step : &TraceAnalysisStepKind
+    ) -> Option<TraceAnalysisFiltrationResult> {
+        match self {
+            TraceAnalysisStepFilter::MaxNodeNumber( max_node_number ) => {
+                if global_state.node_count >= *max_node_number {
+                    return Some( TraceAnalysisFiltrationResult::MaxNodeNumber );
+                }
+            }
+        }
+        None
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+
+
+pub struct TraceAnalysisNodePreFilter {}
+
+impl AbstractNodePreFilter<TraceAnalysisConfig> for TraceAnalysisNodePreFilter {
+
+    fn apply_filter(
+        &self,
+        context_and_param : &TraceAnalysisContextAndParameterization,
+        _global_state : &TraceAnalysisGlobalState,
+        node : &TraceAnalysisNode,
+    ) -> Option<TraceAnalysisFiltrationResult> {
+        if context_and_param.is_fully_consumed(&node.consumed) && node.interaction.express_empty() {
+            if node.has_removed_lifelines {
+                return Some(
+                    TraceAnalysisFiltrationResult::TraceFullyConsumedAfterLifelineRemoval
+                );
+            }
+            return Some(
+                TraceAnalysisFiltrationResult::TraceFullyConsumed
+            );
+        }
+        None
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+}
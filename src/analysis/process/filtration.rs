@@ -0,0 +1,42 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+
+pub enum TraceAnalysisFiltrationResult {
+    // the multi-trace has been entirely consumed and the remaining interaction accepts the empty trace
+    TraceFullyConsumed,
+    // same but some lifelines have been removed on the fly
+    TraceFullyConsumedAfterLifelineRemoval,
+    MaxNodeNumber
+}
+
+impl fmt::Display for TraceAnalysisFiltrationResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceAnalysisFiltrationResult::TraceFullyConsumed => {
+                write!(f,"TraceFullyConsumed")
+            },
+            TraceAnalysisFiltrationResult::TraceFullyConsumedAfterLifelineRemoval => {
+                write!(f,"TraceFullyConsumedAfterLifelineRemoval")
+            },
+            TraceAnalysisFiltrationResult::MaxNodeNumber => {
+                write!(f,"MaxNodeNumber")
+            },
+        }
+    }
+}
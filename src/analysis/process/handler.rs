@@ -0,0 +1,135 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use graph_process_manager_core::process::handler::AbstractAlgorithmOperationHandler;
+use crate::core::semantics::execute::execute_interaction;
+use crate::core::semantics::frontier::{global_frontier, FrontierElement};
+use crate::core::syntax::lang_traits::eliminate_lf::eliminable::LifelineEliminable;
+use crate::core::syntax::lang_traits::involve::involves::InvolvesLifelines;
+
+use super::state::TraceAnalysisGlobalState;
+use super::{conf::TraceAnalysisConfig, context::TraceAnalysisContextAndParameterization, node::TraceAnalysisNode, step::TraceAnalysisStepKind};
+
+pub struct TraceAnalysisHandler {}
+
+impl AbstractAlgorithmOperationHandler<TraceAnalysisConfig> for TraceAnalysisHandler {
+
+    fn process_new_step(
+        _context_and_param : &TraceAnalysisContextAndParameterization,
+        _global_state : &mut TraceAnalysisGlobalState,
+        parent_node : &TraceAnalysisNode,
+        step_to_process : &mut TraceAnalysisStepKind
+    ) -> TraceAnalysisNode {
+        match step_to_process {
+            TraceAnalysisStepKind::ConsumeAction(frt_elt,consumed_components) => {
+                let mut consumed = parent_node.consumed.clone();
+                for comp_idx in consumed_components.iter() {
+                    consumed[*comp_idx] += 1;
+                }
+                TraceAnalysisNode::new(
+                    execute_interaction(
+                        &parent_node.interaction,
+                        &frt_elt.position,
+                        &frt_elt.target_lf_ids,
+                        false
+                    ).interaction,
+                    consumed,
+                    parent_node.has_removed_lifelines
+                )
+            },
+            TraceAnalysisStepKind::RemoveLifelines(lfs_to_remove) => {
+                TraceAnalysisNode::new(
+                    parent_node.interaction.eliminate_lifelines(lfs_to_remove),
+                    parent_node.consumed.clone(),
+                    true
+                )
+            }
+        }
+    }
+
+    fn collect_next_steps(
+        context_and_param : &TraceAnalysisContextAndParameterization,
+        global_state : &mut TraceAnalysisGlobalState,
+        parent_node : &TraceAnalysisNode
+    ) -> Vec<TraceAnalysisStepKind> {
+        // ***
+        // if the node has already been expanded, its successors are already queued or explored
+        if let Some(max_memoized) = context_and_param.max_memoized_nodes {
+            if global_state.memoized.contains(parent_node) {
+                return vec![];
+            }
+            if global_state.memoized.len() < max_memoized {
+                global_state.memoized.insert(parent_node.clone());
+            }
+        }
+        // ***
+        let mut steps = vec![];
+        for frt_elt in global_frontier(&parent_node.interaction, true) {
+            if let Some(consumed_components) = match_frontier_element_with_heads(
+                context_and_param,
+                &parent_node.consumed,
+                &frt_elt
+            ) {
+                steps.push(TraceAnalysisStepKind::ConsumeAction(frt_elt, consumed_components));
+            }
+        }
+        // ***
+        // simulation : as soon as a component is exhausted, the removal of its lifelines is proposed
+        // so that the actions which would have been observed on them after the end of the logging are ignored
+        // it is proposed alongside the consumption of actions
+        // so that the multi-trace can still be fully consumed without removal and be given a Pass
+        if context_and_param.lifeline_removal {
+            let exhausted_lfs = context_and_param.get_lifelines_of_exhausted_components(&parent_node.consumed);
+            let lfs_to_remove : BTreeSet<usize> = parent_node.interaction.lifelines_that_may_be_involved()
+                .intersection(&exhausted_lfs).cloned().collect();
+            if !lfs_to_remove.is_empty() {
+                steps.push(TraceAnalysisStepKind::RemoveLifelines(lfs_to_remove));
+            }
+        }
+        steps
+    }
+
+}
+
+
+
+/**
+ * The lifelines targeted by the frontier element determine which components are involved.
+ * The frontier element matches iff, for each such component,
+ * the head of its remaining part is one of the frontier element's target actions.
+ * Returns the set of involved components if it matches.
+ **/
+fn match_frontier_element_with_heads(
+    context_and_param : &TraceAnalysisContextAndParameterization,
+    consumed : &[usize],
+    frt_elt : &FrontierElement
+) -> Option<BTreeSet<usize>> {
+    let mut involved_components = BTreeSet::new();
+    for lf_id in &frt_elt.target_lf_ids {
+        involved_components.insert(context_and_param.get_component_of_lifeline(*lf_id)?);
+    }
+    for comp_idx in &involved_components {
+        let (_,component) = context_and_param.components.get(*comp_idx).unwrap();
+        let head = component.get(consumed[*comp_idx])?;
+        if !frt_elt.target_actions.contains(head) {
+            return None;
+        }
+    }
+    Some(involved_components)
+}
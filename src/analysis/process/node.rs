@@ -0,0 +1,47 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use graph_process_manager_core::process::config::AbstractNodeKind;
+use crate::core::syntax::interaction::Interaction;
+
+
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TraceAnalysisNode {
+    pub interaction : Interaction,
+    // number of actions that have already been consumed in each component of the multi-trace
+    pub consumed : Vec<usize>,
+    // whether or not some lifelines have been removed on the fly
+    pub has_removed_lifelines : bool
+}
+
+impl TraceAnalysisNode {
+    pub fn new(
+        interaction : Interaction,
+        consumed : Vec<usize>,
+        has_removed_lifelines : bool
+    ) -> Self {
+        Self { interaction, consumed, has_removed_lifelines }
+    }
+}
+
+
+impl AbstractNodeKind for TraceAnalysisNode {
+    fn is_included_for_memoization(&self, memoized_node: &Self) -> bool {
+        self == memoized_node
+    }
+}
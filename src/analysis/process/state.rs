@@ -0,0 +1,96 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashSet;
+
+use graph_process_manager_core::process::persistent_state::AbstractProcessMutablePersistentState;
+
+use crate::analysis::verdict::AnalysisVerdict;
+
+use super::conf::TraceAnalysisConfig;
+use super::context::TraceAnalysisContextAndParameterization;
+use super::filtration::TraceAnalysisFiltrationResult;
+use super::node::TraceAnalysisNode;
+use super::step::TraceAnalysisStepKind;
+
+
+
+pub struct TraceAnalysisGlobalState {
+    pub verdict : AnalysisVerdict,
+    pub node_count : u32,
+    // nodes that have already been expanded
+    // bounded by the context's max_memoized_nodes
+    pub memoized : HashSet<TraceAnalysisNode>
+}
+
+
+
+impl AbstractProcessMutablePersistentState<TraceAnalysisConfig> for TraceAnalysisGlobalState {
+    fn get_initial_state(
+        _context_and_param: &TraceAnalysisContextAndParameterization,
+        _initial_node : &TraceAnalysisNode
+    ) -> Self {
+        Self{verdict : AnalysisVerdict::Fail, node_count : 0, memoized : HashSet::new()}
+    }
+
+    fn update_on_node_reached(
+        &mut self,
+        _context_and_param: &TraceAnalysisContextAndParameterization,
+        _node: &TraceAnalysisNode
+    ) {
+        self.node_count += 1;
+    }
+
+    fn update_on_next_steps_collected_reached(
+        &mut self,
+        _context_and_param: &TraceAnalysisContextAndParameterization,
+        _node: &TraceAnalysisNode,
+        _steps: &[TraceAnalysisStepKind]
+    ) {
+        // nothing
+    }
+
+    fn update_on_filtered(
+        &mut self,
+        _context_and_param: &TraceAnalysisContextAndParameterization,
+        _parent_node: &TraceAnalysisNode,
+        filtration_result: &TraceAnalysisFiltrationResult
+    ) {
+        match filtration_result {
+            TraceAnalysisFiltrationResult::TraceFullyConsumed => {
+                self.verdict = AnalysisVerdict::Pass;
+            },
+            TraceAnalysisFiltrationResult::TraceFullyConsumedAfterLifelineRemoval => {
+                if self.verdict != AnalysisVerdict::Pass {
+                    self.verdict = AnalysisVerdict::WeakPass;
+                }
+            },
+            TraceAnalysisFiltrationResult::MaxNodeNumber => {
+                if self.verdict == AnalysisVerdict::Fail {
+                    self.verdict = AnalysisVerdict::Inconc;
+                }
+            }
+        }
+    }
+
+    fn warrants_termination_of_the_process(
+        &self,
+        _context_and_param: &TraceAnalysisContextAndParameterization
+    ) -> bool {
+        self.verdict == AnalysisVerdict::Pass
+    }
+}
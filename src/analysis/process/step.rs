@@ -0,0 +1,32 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use crate::core::semantics::frontier::FrontierElement;
+
+
+
+pub enum TraceAnalysisStepKind {
+    // execute on the interaction the frontier element which matches the heads of some components
+    ConsumeAction(
+        FrontierElement, // the frontier element to execute on the interaction
+        BTreeSet<usize> // the components the heads of which are consumed
+    ),
+    // eliminate from the interaction the lifelines of exhausted components
+    RemoveLifelines(BTreeSet<usize>)
+}
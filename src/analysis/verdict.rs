@@ -0,0 +1,50 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+/**
+ * Verdict of the analysis of a trace w.r.t. an interaction.
+ * - Pass : the trace is accepted by the interaction
+ * - WeakPass : the trace is accepted as a (multi-)prefix, some lifelines having been removed on the fly
+ * - Fail : the trace is not accepted by the interaction
+ * - Inconc : the exploration was truncated (e.g. node budget exhausted) before any acceptance could be found
+ **/
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum AnalysisVerdict {
+    Pass,
+    WeakPass,
+    Fail,
+    Inconc
+}
+
+impl std::fmt::Display for AnalysisVerdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnalysisVerdict::Pass => {
+                write!(f, "Pass")
+            },
+            AnalysisVerdict::WeakPass => {
+                write!(f, "WeakPass")
+            },
+            AnalysisVerdict::Fail => {
+                write!(f, "Fail")
+            },
+            AnalysisVerdict::Inconc => {
+                write!(f, "Inconc")
+            },
+        }
+    }
+}
@@ -0,0 +1,170 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use autour_core::dfa::dfa::AutDFA;
+
+use crate::core::semantics::execute::execute_interaction;
+use crate::core::semantics::frontier::global_frontier;
+use crate::core::semantics::trace_action::TraceAction;
+use crate::core::syntax::interaction::Interaction;
+use crate::core::syntax::lang_traits::actions::contains::ContainsTraceActions;
+use crate::equivalence_checker::check_equivalence::{InclusionDirection, InteractionEquivalenceGlobalVerdict};
+use crate::inclusion_checker::check_inclusion::InteractionInclusionGlobalVerdict;
+
+use super::compile::interaction_to_automaton_over_alphabet;
+use super::options::InteractionAutomatonOptions;
+
+
+
+/**
+ * Checks inclusion by compiling both interactions into automata.
+ * The verdict is exact if the state space of the included interaction is finite (up to the merging of states)
+ * and fits in the maximum number of states.
+ * Otherwise, the verdict may be *IsIncludedUpToExploredSemantics*.
+ * If inclusion does not hold, the counterexample is a shortest trace accepted by the included interaction
+ * and not by the including one.
+ **/
+pub fn check_inclusion_via_automata(
+    included : &Interaction,
+    including : &Interaction,
+    options : &InteractionAutomatonOptions
+) -> InteractionInclusionGlobalVerdict {
+    let alphabet : HashSet<TraceAction> = included.get_all_trace_actions().into_iter()
+        .chain(including.get_all_trace_actions())
+        .collect();
+    let included_aut = interaction_to_automaton_over_alphabet(included, &alphabet, options);
+    let including_aut = interaction_to_automaton_over_alphabet(including, &alphabet, options);
+    match find_shortest_trace_in_difference(&included_aut.to_dfa(), &including_aut.to_dfa()) {
+        None => {
+            // the including automaton under-approximates the including interaction
+            if included_aut.is_complete {
+                InteractionInclusionGlobalVerdict::IsIncluded
+            } else {
+                InteractionInclusionGlobalVerdict::IsIncludedUpToExploredSemantics
+            }
+        },
+        Some(trace) => {
+            // the trace is accepted by the included interaction
+            // but it may only be rejected by the explored part of the including one
+            if including_aut.is_complete || !accepts_trace(including, &trace) {
+                InteractionInclusionGlobalVerdict::IsNotIncluded(trace)
+            } else {
+                InteractionInclusionGlobalVerdict::IsIncludedUpToExploredSemantics
+            }
+        }
+    }
+}
+
+/**
+ * Checks inclusion in both directions by compiling the interactions into automata.
+ **/
+pub fn check_equivalence_via_automata(
+    left : &Interaction,
+    right : &Interaction,
+    options : &InteractionAutomatonOptions
+) -> InteractionEquivalenceGlobalVerdict {
+    let left_in_right = check_inclusion_via_automata(left, right, options);
+    if let InteractionInclusionGlobalVerdict::IsNotIncluded(trace) = left_in_right {
+        return InteractionEquivalenceGlobalVerdict::NotEquivalent(InclusionDirection::LeftNotIncludedInRight, trace);
+    }
+    // ***
+    let right_in_left = check_inclusion_via_automata(right, left, options);
+    match (left_in_right,right_in_left) {
+        (_,InteractionInclusionGlobalVerdict::IsNotIncluded(trace)) => {
+            InteractionEquivalenceGlobalVerdict::NotEquivalent(InclusionDirection::RightNotIncludedInLeft, trace)
+        },
+        (InteractionInclusionGlobalVerdict::IsIncluded,InteractionInclusionGlobalVerdict::IsIncluded) => {
+            InteractionEquivalenceGlobalVerdict::Equivalent
+        },
+        _ => {
+            InteractionEquivalenceGlobalVerdict::EquivalentUpToExploredSemantics
+        }
+    }
+}
+
+
+
+// a state of the first DFA and a state of the second DFA, if not in its sink state
+type ProductState = (usize,Option<usize>);
+
+/**
+ * Breadth-first search in the product of the two DFA for a trace accepted by the first one and not by the second one.
+ * A missing transition in the second DFA leads to a rejecting sink state.
+ **/
+fn find_shortest_trace_in_difference(
+    accepting : &AutDFA<TraceAction>,
+    rejecting : &AutDFA<TraceAction>
+) -> Option<Vec<TraceAction>> {
+    let letters : BTreeSet<TraceAction> = accepting.alphabet.iter().copied().collect();
+    let initial = (accepting.initial,Some(rejecting.initial));
+    let mut predecessors : HashMap<ProductState,Option<(ProductState,TraceAction)>> = HashMap::from([(initial,None)]);
+    let mut queue = VecDeque::from([initial]);
+    while let Some(pair) = queue.pop_front() {
+        let (acc_state,rej_state) = pair;
+        let rejected = match rej_state {
+            None => {
+                true
+            },
+            Some(rej_state) => {
+                !rejecting.finals.contains(&rej_state)
+            }
+        };
+        if accepting.finals.contains(&acc_state) && rejected {
+            let mut trace = vec![];
+            let mut current = pair;
+            while let Some((previous,action)) = predecessors.get(&current).unwrap() {
+                trace.push(*action);
+                current = *previous;
+            }
+            trace.reverse();
+            return Some(trace);
+        }
+        for letter in &letters {
+            if let Some(acc_target) = accepting.transitions[acc_state].get(letter) {
+                let rej_target = rej_state.and_then(|rej_state| rejecting.transitions[rej_state].get(letter).copied());
+                let next = (*acc_target,rej_target);
+                if let Entry::Vacant(entry) = predecessors.entry(next) {
+                    entry.insert(Some((pair,*letter)));
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    None
+}
+
+// keeps track of all the follow-ups reached via the trace
+fn accepts_trace(int : &Interaction, trace : &[TraceAction]) -> bool {
+    let mut follow_ups = HashSet::from([int.clone()]);
+    for action in trace {
+        let mut next_follow_ups = HashSet::new();
+        for follow_up in &follow_ups {
+            for frt_elt in global_frontier(follow_up, false) {
+                if frt_elt.target_actions.contains(action) {
+                    next_follow_ups.insert(
+                        execute_interaction(follow_up, &frt_elt.position, &frt_elt.target_lf_ids, false).interaction
+                    );
+                }
+            }
+        }
+        follow_ups = next_follow_ups;
+    }
+    follow_ups.iter().any(|follow_up| follow_up.express_empty())
+}
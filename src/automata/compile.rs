@@ -0,0 +1,140 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use autour_core::dfa::dfa::AutDFA;
+use autour_core::nfa::nfa::AutNFA;
+use autour_core::traits::transform::AutTransformable;
+use autour_core::traits::translate::AutTranslatable;
+
+use crate::core::semantics::execute::execute_interaction;
+use crate::core::semantics::frontier::global_frontier;
+use crate::core::semantics::trace_action::TraceAction;
+use crate::core::syntax::interaction::Interaction;
+use crate::core::syntax::lang_traits::actions::contains::ContainsTraceActions;
+use crate::rewriting::canonize::canonize_interaction;
+
+use super::options::InteractionAutomatonOptions;
+
+
+
+/**
+ * A NFA over trace actions which states are follow-ups of an interaction.
+ * The initial state is the state 0 i.e. the (possibly canonized) interaction itself
+ * and a state is final if its follow-up can terminate.
+ * If the exploration has been stopped before reaching all the follow-ups,
+ * the automaton is not complete and only accepts some of the traces of the interaction.
+ **/
+#[derive(Clone, Debug)]
+pub struct InteractionAutomaton {
+    pub nfa : AutNFA<TraceAction>,
+    // the follow-up interaction associated with each state
+    pub states : Vec<Interaction>,
+    pub is_complete : bool
+}
+
+impl InteractionAutomaton {
+
+    pub fn to_dfa(&self) -> AutDFA<TraceAction> {
+        self.nfa.to_dfa()
+    }
+
+    pub fn to_minimal_dfa(&self) -> AutDFA<TraceAction> {
+        self.nfa.to_dfa().minimize()
+    }
+
+}
+
+
+
+/**
+ * Compiles the interaction into a NFA which alphabet is the set of actions occurring in the interaction.
+ **/
+pub fn interaction_to_automaton(
+    int : &Interaction,
+    options : &InteractionAutomatonOptions
+) -> InteractionAutomaton {
+    interaction_to_automaton_over_alphabet(int, &HashSet::new(), options)
+}
+
+/**
+ * Compiles the interaction into a NFA which alphabet is the given one,
+ * extended with the actions occurring in the interaction.
+ * Automata must be defined over the same alphabet to be compared.
+ **/
+pub fn interaction_to_automaton_over_alphabet(
+    int : &Interaction,
+    alphabet : &HashSet<TraceAction>,
+    options : &InteractionAutomatonOptions
+) -> InteractionAutomaton {
+    let mut alphabet = alphabet.clone();
+    alphabet.extend(int.get_all_trace_actions());
+    // ***
+    let normalize = |follow_up : Interaction| {
+        if options.canonize_states {
+            canonize_interaction(&follow_up, None, true, true)
+        } else {
+            follow_up
+        }
+    };
+    let mut states = vec![normalize(int.clone())];
+    let mut states_ids : HashMap<Interaction,usize> = HashMap::from([(states[0].clone(),0)]);
+    let mut transitions : Vec<HashMap<TraceAction,HashSet<usize>>> = vec![HashMap::new()];
+    let mut is_complete = true;
+    let mut queue = VecDeque::from([0]);
+    while let Some(state_id) = queue.pop_front() {
+        let state = states[state_id].clone();
+        for frt_elt in global_frontier(&state, false) {
+            let follow_up = normalize(
+                execute_interaction(
+                    &state,
+                    &frt_elt.position,
+                    &frt_elt.target_lf_ids,
+                    false
+                ).interaction
+            );
+            let target_id = match states_ids.get(&follow_up) {
+                Some(target_id) => {
+                    *target_id
+                },
+                None => {
+                    if options.max_state_number.is_some_and(|max| states.len() as u32 >= max) {
+                        is_complete = false;
+                        continue;
+                    }
+                    let target_id = states.len();
+                    states_ids.insert(follow_up.clone(), target_id);
+                    states.push(follow_up);
+                    transitions.push(HashMap::new());
+                    queue.push_back(target_id);
+                    target_id
+                }
+            };
+            for action in &frt_elt.target_actions {
+                transitions[state_id].entry(*action).or_default().insert(target_id);
+            }
+        }
+    }
+    // ***
+    let finals = states.iter().enumerate()
+        .filter(|(_,state)| state.express_empty())
+        .map(|(state_id,_)| state_id)
+        .collect();
+    let nfa = AutNFA::from_raw(alphabet, HashSet::from([0]), finals, transitions).unwrap();
+    InteractionAutomaton { nfa, states, is_complete }
+}
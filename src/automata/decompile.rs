@@ -0,0 +1,220 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap};
+
+use autour_core::bre::bre::ExpBRE;
+use autour_core::bre::term::TermBRE;
+use autour_core::nfa::nfa::AutNFA;
+
+use crate::core::semantics::trace_action::{TraceAction, TraceActionKind};
+use crate::core::syntax::action::{EmissionAction, ReceptionAction};
+use crate::core::syntax::interaction::{Interaction, LoopKind};
+use crate::rewriting::canonize::canonize_interaction;
+
+
+
+/**
+ * The interaction which only accepts the trace made of the action.
+ * Emissions have no target gates and receptions no origin gate.
+ **/
+pub fn trace_action_to_interaction(action : &TraceAction) -> Interaction {
+    match action.act_kind {
+        TraceActionKind::Emission => {
+            Interaction::Emission(EmissionAction::new(action.lf_id, action.ms_id, vec![]))
+        },
+        TraceActionKind::Reception => {
+            Interaction::Reception(ReceptionAction::new(None, action.ms_id, action.lf_id))
+        }
+    }
+}
+
+/**
+ * Translates a regular expression term over trace actions into an interaction
+ * using strict sequencing for concatenation, alternatives for unions and loopS for Kleene stars.
+ * Returns None if the term denotes the empty language, which cannot be expressed by an interaction.
+ * The resulting interaction is not simplified.
+ **/
+pub fn regex_term_to_interaction(term : &TermBRE<TraceAction>) -> Option<Interaction> {
+    match term {
+        TermBRE::Empty => {
+            None
+        },
+        TermBRE::Epsilon => {
+            Some(Interaction::Empty)
+        },
+        TermBRE::Literal(action) => {
+            Some(trace_action_to_interaction(action))
+        },
+        TermBRE::Union(sub_terms) => {
+            // empty summands can be ignored
+            let summands : Vec<Interaction> = sub_terms.iter().filter_map(regex_term_to_interaction).collect();
+            fold_right(summands, Interaction::Alt)
+        },
+        TermBRE::Concat(sub_terms) => {
+            // a single empty factor makes the whole concatenation empty
+            let factors : Vec<Interaction> = sub_terms.iter().map(regex_term_to_interaction).collect::<Option<Vec<Interaction>>>()?;
+            Some(fold_right(factors, Interaction::Strict).unwrap_or(Interaction::Empty))
+        },
+        TermBRE::Kleene(sub_term) => {
+            match regex_term_to_interaction(sub_term) {
+                None | Some(Interaction::Empty) => {
+                    Some(Interaction::Empty)
+                },
+                Some(i1) => {
+                    Some(Interaction::Loop(LoopKind::SStrictSeq, Box::new(i1)))
+                }
+            }
+        }
+    }
+}
+
+fn fold_right(
+    sub_ints : Vec<Interaction>,
+    operator : fn(Box<Interaction>,Box<Interaction>) -> Interaction
+) -> Option<Interaction> {
+    sub_ints.into_iter().rev().reduce(|acc, i1| operator(Box::new(i1), Box::new(acc)))
+}
+
+/**
+ * Translates a regular expression over trace actions into a simplified interaction.
+ * Returns None if the expression denotes the empty language.
+ **/
+pub fn regex_to_interaction(regex : &ExpBRE<TraceAction>) -> Option<Interaction> {
+    regex_term_to_interaction(&regex.term).map(
+        |int| canonize_interaction(&int, None, true, true)
+    )
+}
+
+/**
+ * Translates a NFA over trace actions into a simplified interaction accepting the same traces.
+ * Returns None if the NFA accepts no trace.
+ **/
+pub fn nfa_to_interaction(nfa : &AutNFA<TraceAction>) -> Option<Interaction> {
+    regex_to_interaction(&nfa_to_regex(nfa))
+}
+
+/**
+ * Computes a regular expression accepting the same traces as the NFA via state elimination.
+ * A new initial state and a new final state are added and the states of the NFA are then removed one by one,
+ * each time choosing a state which removal creates the fewest new transitions.
+ **/
+pub fn nfa_to_regex(nfa : &AutNFA<TraceAction>) -> ExpBRE<TraceAction> {
+    let start = nfa.transitions.len();
+    let accept = start + 1;
+    let mut edges : HashMap<(usize,usize),TermBRE<TraceAction>> = HashMap::new();
+    for init in &nfa.initials {
+        add_edge(&mut edges, start, *init, TermBRE::Epsilon);
+    }
+    for fin in &nfa.finals {
+        add_edge(&mut edges, *fin, accept, TermBRE::Epsilon);
+    }
+    for (orig, outgoing) in nfa.transitions.iter().enumerate() {
+        for (action, targets) in outgoing {
+            for targ in targets {
+                add_edge(&mut edges, orig, *targ, TermBRE::Literal(*action));
+            }
+        }
+    }
+    // ***
+    let mut remaining : BTreeSet<usize> = (0..start).collect();
+    while let Some(to_rip) = remaining.iter().min_by_key(|st| (count_new_edges(&edges, **st), **st)).cloned() {
+        remaining.remove(&to_rip);
+        let self_loop = match edges.remove(&(to_rip,to_rip)) {
+            None => TermBRE::Epsilon,
+            Some(term) => kleene(term)
+        };
+        let incoming : Vec<(usize,TermBRE<TraceAction>)> = extract_edges(&mut edges, |(_,targ)| *targ == to_rip)
+            .into_iter().map(|((orig,_),term)| (orig,term)).collect();
+        let outgoing : Vec<(usize,TermBRE<TraceAction>)> = extract_edges(&mut edges, |(orig,_)| *orig == to_rip)
+            .into_iter().map(|((_,targ),term)| (targ,term)).collect();
+        for (orig, in_term) in &incoming {
+            for (targ, out_term) in &outgoing {
+                let term = in_term.clone()
+                    .concatenate(self_loop.clone())
+                    .concatenate(out_term.clone());
+                add_edge(&mut edges, *orig, *targ, term);
+            }
+        }
+    }
+    // ***
+    let term = edges.remove(&(start,accept)).unwrap_or(TermBRE::Empty);
+    ExpBRE::from_raw(nfa.alphabet.clone(), term).unwrap()
+}
+
+fn add_edge(
+    edges : &mut HashMap<(usize,usize),TermBRE<TraceAction>>,
+    orig : usize,
+    targ : usize,
+    term : TermBRE<TraceAction>
+) {
+    if term.is_empty() {
+        return;
+    }
+    let term = match edges.remove(&(orig,targ)) {
+        None => {
+            term
+        },
+        Some(existing) => {
+            existing.unite(term)
+        }
+    };
+    edges.insert((orig,targ), term);
+}
+
+fn extract_edges<F : Fn(&(usize,usize)) -> bool>(
+    edges : &mut HashMap<(usize,usize),TermBRE<TraceAction>>,
+    filter : F
+) -> Vec<((usize,usize),TermBRE<TraceAction>)> {
+    let keys : Vec<(usize,usize)> = edges.keys().filter(|k| filter(k)).cloned().collect();
+    keys.into_iter().map(|k| {
+        let term = edges.remove(&k).unwrap();
+        (k,term)
+    }).collect()
+}
+
+fn count_new_edges(
+    edges : &HashMap<(usize,usize),TermBRE<TraceAction>>,
+    state : usize
+) -> usize {
+    let in_num = edges.keys().filter(|(orig,targ)| *targ == state && *orig != state).count();
+    let out_num = edges.keys().filter(|(orig,targ)| *orig == state && *targ != state).count();
+    in_num * out_num
+}
+
+// Kleene star with the simplifications (a*)* = a* and (ε|a)* = a*
+fn kleene(term : TermBRE<TraceAction>) -> TermBRE<TraceAction> {
+    match term {
+        TermBRE::Empty | TermBRE::Epsilon => {
+            TermBRE::Epsilon
+        },
+        TermBRE::Kleene(_) => {
+            term
+        },
+        TermBRE::Union(mut sub_terms) => {
+            if sub_terms.remove(&TermBRE::Epsilon) {
+                let rem = sub_terms.into_iter().fold(TermBRE::Empty, |acc, t| acc.unite(t));
+                kleene(rem)
+            } else {
+                TermBRE::Kleene(Box::new(TermBRE::Union(sub_terms)))
+            }
+        },
+        _ => {
+            TermBRE::Kleene(Box::new(term))
+        }
+    }
+}
@@ -0,0 +1,48 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+/**
+ * Options of the compilation of interactions into finite automata.
+ * The state space of an interaction may be infinite (e.g. with weak loops),
+ * in which case the exploration is stopped once the maximum number of states is reached
+ * and the automaton only under-approximates the semantics of the interaction.
+ **/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InteractionAutomatonOptions {
+    // if set, no new state is created once that many states have been reached
+    pub max_state_number : Option<u32>,
+    // whether or not follow-up interactions are put in canonical form so that equivalent states are merged
+    // otherwise only syntactically equal follow-ups are merged
+    pub canonize_states : bool
+}
+
+impl InteractionAutomatonOptions {
+
+    pub fn new(
+        max_state_number : Option<u32>,
+        canonize_states : bool
+    ) -> Self {
+        Self { max_state_number, canonize_states }
+    }
+
+}
+
+impl Default for InteractionAutomatonOptions {
+    fn default() -> Self {
+        Self::new(Some(1000), true)
+    }
+}
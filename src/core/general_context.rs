@@ -108,4 +108,22 @@ impl GeneralContext {
         self.gt_names.get(gt_id)
     }
 
+    // for display purposes, elements which are not declared are named after their id
+    // with a "#" so that they cannot be mistaken for declared ones
+    // these names are display-only : the parsers do not accept them and the text cannot be read back
+
+    pub fn get_lf_name_or_fallback(&self, lf_id : usize) -> String {
+        match self.get_lf_name(lf_id) {
+            Some(lf_name) => lf_name.clone(),
+            None => format!("lf#{}", lf_id)
+        }
+    }
+
+    pub fn get_ms_name_or_fallback(&self, ms_id : usize) -> String {
+        match self.get_ms_name(ms_id) {
+            Some(ms_name) => ms_name.clone(),
+            None => format!("ms#{}", ms_id)
+        }
+    }
+
 }
@@ -0,0 +1,117 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use crate::core::semantics::execute::execute_interaction;
+use crate::core::semantics::frontier::global_frontier;
+use crate::core::semantics::trace_action::TraceAction;
+use crate::core::syntax::interaction::Interaction;
+
+
+
+/**
+ * Enumerates the traces accepted by the interaction which have at most "max_len" actions.
+ * If "max_loop_depth" is set, the enumeration only explores executions in which
+ * the sum of the loop depths of the executed actions does not exceed it.
+ * Each trace is yielded once, by increasing length, and in lexicographic order among traces of the same length.
+ * Traces are computed lazily, one length at a time.
+ **/
+pub fn accepted_traces(
+    interaction : &Interaction,
+    max_len : u32,
+    max_loop_depth : Option<u32>
+) -> impl Iterator<Item = Vec<TraceAction>> {
+    AcceptedTraces {
+        max_len,
+        max_loop_depth,
+        current_len : 0,
+        current_states : vec![(vec![],interaction.clone(),0)],
+        pending_traces : VecDeque::new()
+    }
+}
+
+
+
+struct AcceptedTraces {
+    max_len : u32,
+    max_loop_depth : Option<u32>,
+    // the length of the traces which lead to the current states
+    current_len : u32,
+    // the current states as (trace, follow-up interaction, loop depth)
+    current_states : Vec<(Vec<TraceAction>,Interaction,u32)>,
+    // accepted traces of the current length that have not been yielded yet
+    pending_traces : VecDeque<Vec<TraceAction>>
+}
+
+impl AcceptedTraces {
+
+    fn explore_next_len(&mut self) {
+        let accepted : BTreeSet<Vec<TraceAction>> = self.current_states.iter()
+            .filter(|(_,int,_)| int.express_empty())
+            .map(|(trace,_,_)| trace.clone())
+            .collect();
+        self.pending_traces.extend(accepted);
+        if self.current_len == self.max_len {
+            self.current_states.clear();
+            return;
+        }
+        // the same follow-up reached via the same trace is only explored once, with the smallest loop depth
+        let mut next_states : BTreeMap<(Vec<TraceAction>,Interaction),u32> = BTreeMap::new();
+        for (trace,int,loop_depth) in self.current_states.drain(..) {
+            for frt_elt in global_frontier(&int, false) {
+                let next_loop_depth = loop_depth + frt_elt.max_loop_depth;
+                if self.max_loop_depth.is_some_and(|max| next_loop_depth > max) {
+                    continue;
+                }
+                let follow_up = execute_interaction(
+                    &int,
+                    &frt_elt.position,
+                    &frt_elt.target_lf_ids,
+                    false
+                ).interaction;
+                for action in &frt_elt.target_actions {
+                    let mut next_trace = trace.clone();
+                    next_trace.push(*action);
+                    let depth = next_states.entry((next_trace,follow_up.clone())).or_insert(next_loop_depth);
+                    *depth = (*depth).min(next_loop_depth);
+                }
+            }
+        }
+        self.current_states = next_states.into_iter().map(
+            |((trace,int),loop_depth)| (trace,int,loop_depth)
+        ).collect();
+        self.current_len += 1;
+    }
+
+}
+
+impl Iterator for AcceptedTraces {
+    type Item = Vec<TraceAction>;
+
+    fn next(&mut self) -> Option<Vec<TraceAction>> {
+        loop {
+            if let Some(trace) = self.pending_traces.pop_front() {
+                return Some(trace);
+            }
+            if self.current_states.is_empty() {
+                return None;
+            }
+            self.explore_next_len();
+        }
+    }
+}
@@ -24,6 +24,7 @@ use crate::core::syntax::lang_traits::involve::involves::InvolvesLifelines;
 use crate::core::semantics::position::Position;
 use crate::core::syntax::lang_traits::prune::prunable::LifelinePrunable;
 use crate::core::syntax::interaction::{Interaction, LoopKind};
+use crate::error::HibouError;
 
 
 pub struct ExecutionResult {
@@ -156,34 +157,11 @@ fn execute_interaction_left(my_int : &Interaction,
             }
             return ExecutionResult::new(new_i,affected);
         },
-        /*Interaction::Sync(sync_acts,i1,i2) => {
+        Interaction::Sync(sync_acts,i1,i2) => {
             let exres1 = execute_interaction(i1,sub_p1,tar_lf_ids,get_affected);
-            // ***
-            let acts1 = exres1.interaction.get_all_trace_actions();
-            let acts2 = i2.get_all_trace_actions();
-            // ***
-            let sync_acts_as_set : BTreeSet<TraceAction> = BTreeSet::from_iter(sync_acts.iter().cloned());
-            let intersetc1 = sync_acts_as_set.intersection(&acts1).count();
-            let intersetc2 = sync_acts_as_set.intersection(&acts2).count();
-            // ***
-            let new_i : Interaction;
-            if intersetc1 == 0 && intersetc2 == 0 {
-                if exres1.interaction == Interaction::Empty {
-                    new_i = *i2.clone();
-                } else if **i2 == Interaction::Empty {
-                    new_i = exres1.interaction;
-                } else {
-                    new_i = Interaction::Par(Box::new(exres1.interaction),
-                                             Box::new(*i2.clone()));
-                }
-            } else {
-                new_i = Interaction::Sync(sync_acts.clone(),
-                                          Box::new(exres1.interaction),
-                                          Box::new(*i2.clone()));
-            }
-            // ***
-            return ExecutionResult::new(new_i,exres1.affected_lifelines);
-        },*/
+            let new_i = Interaction::sync_or_interleave(sync_acts, exres1.interaction, *i2.clone());
+            ExecutionResult::new(new_i,exres1.affected_lifelines)
+        },
         _ => {
             panic!();
         }
@@ -263,34 +241,11 @@ fn execute_interaction_right(my_int : &Interaction,
                 }
             }
         },
-        /*Interaction::Sync(sync_acts, i1,i2) => {
+        Interaction::Sync(sync_acts, i1,i2) => {
             let exres2 = execute_interaction(i2,sub_p2, tar_lf_ids,get_affected);
-            // ***
-            let acts1 = i1.get_all_trace_actions();
-            let acts2 = exres2.interaction.get_all_trace_actions();
-            // ***
-            let sync_acts_as_set : BTreeSet<TraceAction> = BTreeSet::from_iter(sync_acts.iter().cloned());
-            let intersetc1 = sync_acts_as_set.intersection(&acts1).count();
-            let intersetc2 = sync_acts_as_set.intersection(&acts2).count();
-            // ***
-            let new_i : Interaction;
-            if intersetc1 == 0 && intersetc2 == 0 {
-                if **i1 == Interaction::Empty {
-                    new_i = exres2.interaction;
-                } else if exres2.interaction == Interaction::Empty {
-                    new_i = *i1.clone();
-                } else {
-                    new_i = Interaction::Par(Box::new(*i1.clone()),
-                                             Box::new(exres2.interaction));
-                }
-            } else {
-                new_i = Interaction::Sync(sync_acts.clone(),
-                                          Box::new(*i1.clone()),
-                                          Box::new(exres2.interaction));
-            }
-            // ***
-            return ExecutionResult::new(new_i,exres2.affected_lifelines);
-        },*/
+            let new_i = Interaction::sync_or_interleave(sync_acts, *i1.clone(), exres2.interaction);
+            ExecutionResult::new(new_i,exres2.affected_lifelines)
+        },
         _ => {
             panic!("trying to execute right on {:?}", my_int);
         }
@@ -318,38 +273,31 @@ fn execute_interaction_both(my_int : &Interaction,
                                        new_aff);
             }
         },
-        /*Interaction::Sync(sync_acts, i1, i2) => {
+        Interaction::And(i1,i2) => {
             let exres1 = execute_interaction(i1,sub_p1, tar_lf_ids,get_affected);
             let exres2 = execute_interaction(i2,sub_p2, tar_lf_ids,get_affected);
             // ***
-            let acts1 = exres1.interaction.get_all_trace_actions();
-            let acts2 = exres2.interaction.get_all_trace_actions();
-            // ***
-            let sync_acts_as_set : BTreeSet<TraceAction> = BTreeSet::from_iter(sync_acts.iter().cloned());
-            let intersetc1 = sync_acts_as_set.intersection(&acts1).count();
-            let intersetc2 = sync_acts_as_set.intersection(&acts2).count();
+            let mut new_aff = exres1.affected_lifelines;
+            new_aff.extend(exres2.affected_lifelines);
             // ***
-            let new_i : Interaction;
-            if intersetc1 == 0 && intersetc2 == 0 {
-                if exres1.interaction == Interaction::Empty {
-                    new_i = exres2.interaction;
-                } else if exres2.interaction == Interaction::Empty {
-                    new_i = exres1.interaction;
-                } else {
-                    new_i = Interaction::Par(Box::new(exres1.interaction),
-                                             Box::new(exres2.interaction));
-                }
+            if exres1.interaction == Interaction::Empty && exres2.interaction == Interaction::Empty {
+                ExecutionResult::new(Interaction::Empty,new_aff)
             } else {
-                new_i = Interaction::Sync(sync_acts.clone(),
-                                          Box::new(exres1.interaction),
-                                          Box::new(exres2.interaction));
+                ExecutionResult::new(Interaction::And(Box::new(exres1.interaction),
+                                                      Box::new(exres2.interaction)),
+                                     new_aff)
             }
+        },
+        Interaction::Sync(sync_acts, i1, i2) => {
+            let exres1 = execute_interaction(i1,sub_p1, tar_lf_ids,get_affected);
+            let exres2 = execute_interaction(i2,sub_p2, tar_lf_ids,get_affected);
             // ***
             let mut new_aff = exres1.affected_lifelines;
             new_aff.extend(exres2.affected_lifelines);
             // ***
-            return ExecutionResult::new(new_i,new_aff);
-        },*/
+            let new_i = Interaction::sync_or_interleave(sync_acts, exres1.interaction, exres2.interaction);
+            ExecutionResult::new(new_i,new_aff)
+        },
         _ => {
             panic!("trying to execute both left and right on {:?}", my_int);
         }
@@ -374,4 +322,56 @@ pub fn execute_interaction(my_int : &Interaction,
             return execute_interaction_both(my_int,p1,p2,tar_lf_ids,get_affected);
         }
     }
-}
\ No newline at end of file
+}
+
+
+/**
+ * Whether or not the position designates an action of the interaction
+ * and only goes through operators from which the corresponding sub-interactions
+ * can be executed.
+ **/
+fn is_position_executable(my_int : &Interaction,
+                          my_pos : &Position) -> bool {
+    match (my_pos,my_int) {
+        (Position::Epsilon,Interaction::Emission(_)) |
+        (Position::Epsilon,Interaction::Reception(_)) => {
+            true
+        },
+        (Position::Left(p1),Interaction::Alt(i1,_)) |
+        (Position::Left(p1),Interaction::Loop(_,i1)) |
+        (Position::Left(p1),Interaction::Strict(i1,_)) |
+        (Position::Left(p1),Interaction::CoReg(_,i1,_)) |
+        (Position::Left(p1),Interaction::Sync(_,i1,_)) => {
+            is_position_executable(i1,p1)
+        },
+        (Position::Right(p2),Interaction::Alt(_,i2)) |
+        (Position::Right(p2),Interaction::Strict(_,i2)) |
+        (Position::Right(p2),Interaction::CoReg(_,_,i2)) |
+        (Position::Right(p2),Interaction::Sync(_,_,i2)) => {
+            is_position_executable(i2,p2)
+        },
+        (Position::Both(p1,p2),Interaction::Alt(i1,i2)) |
+        (Position::Both(p1,p2),Interaction::And(i1,i2)) |
+        (Position::Both(p1,p2),Interaction::Sync(_,i1,i2)) => {
+            is_position_executable(i1,p1) && is_position_executable(i2,p2)
+        },
+        _ => {
+            false
+        }
+    }
+}
+
+/**
+ * Fallible variant of *execute_interaction* which returns an error
+ * instead of panicking when the position does not fit the interaction.
+ **/
+pub fn try_execute_interaction(my_int : &Interaction,
+                               my_pos : &Position,
+                               tar_lf_ids : &BTreeSet<usize>,
+                               get_affected : bool) -> Result<ExecutionResult,HibouError> {
+    if is_position_executable(my_int,my_pos) {
+        Ok(execute_interaction(my_int,my_pos,tar_lf_ids,get_affected))
+    } else {
+        Err(HibouError::InvalidPosition(my_pos.clone()))
+    }
+}
@@ -169,8 +169,8 @@ fn global_frontier_rec(delayed_alt : bool, interaction : &Interaction, loop_dept
                 return front;
             }
         },
-        /*Interaction::Sync(ref sync_acts,ref i1, ref i2) => {
-            let sync_acts_as_set : BTreeSet<TraceAction> = BTreeSet::from_iter(sync_acts.iter().cloned());
+        Interaction::Sync(ref sync_acts,ref i1, ref i2) => {
+            let sync_acts_as_set : BTreeSet<TraceAction> = sync_acts.iter().cloned().collect();
             // ***
             let mut new_front = vec![];
             let mut rem_frt1 = vec![];
@@ -220,12 +220,31 @@ fn global_frontier_rec(delayed_alt : bool, interaction : &Interaction, loop_dept
             }
             // ***
             new_front
-        },*/
+        },
         Interaction::Loop(_, ref i1) => {
             return push_frontier_left( &mut global_frontier_rec(delayed_alt,i1,loop_depth+1) );
         },
-        Interaction::And(_,_)=> {
-            panic!("non-conform interaction");
+        Interaction::And(ref i1, ref i2) => {
+            // an action is enabled in the intersection iff it is enabled in both operands
+            let frt1 = global_frontier_rec(delayed_alt,i1,loop_depth);
+            let frt2 = global_frontier_rec(delayed_alt,i2,loop_depth);
+            // ***
+            let mut new_front = vec![];
+            for frt1_elt in &frt1 {
+                for frt2_elt in &frt2 {
+                    if frt1_elt.target_actions == frt2_elt.target_actions {
+                        let new_pos = Position::Both( Box::new(frt1_elt.position.clone()), Box::new(frt2_elt.position.clone()));
+                        let new_target_lf_ids : BTreeSet<usize> = frt1_elt.target_lf_ids.union(&frt2_elt.target_lf_ids).cloned().collect();
+                        let new_max_loop_depth = frt1_elt.max_loop_depth.max(frt2_elt.max_loop_depth);
+                        // ***
+                        new_front.push( FrontierElement::new(new_pos,
+                                                             new_target_lf_ids,
+                                                             frt1_elt.target_actions.clone(),
+                                                             new_max_loop_depth ));
+                    }
+                }
+            }
+            new_front
         }
     }
 }
@@ -17,4 +17,7 @@ limitations under the License.
 pub mod position;
 pub mod trace_action;
 pub mod frontier;
-pub mod execute;
\ No newline at end of file
+pub mod execute;
+pub mod multitrace;
+pub mod accepted_traces;
+
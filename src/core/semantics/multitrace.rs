@@ -0,0 +1,53 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::core::semantics::trace_action::TraceAction;
+
+
+
+/**
+ * A multi-trace is a collection of trace components, each of which is a local log
+ * collected on a set of co-localized lifelines.
+ * The sets of lifelines of the different components are expected to be pairwise disjoint.
+ **/
+pub type MultiTrace = BTreeMap<BTreeSet<usize>, Vec<TraceAction>>;
+
+/**
+ * Builds the multi-trace with a single component over the given lifelines
+ * i.e. the representation of a global trace.
+ **/
+pub fn multitrace_from_global_trace(lf_ids : BTreeSet<usize>, trace : &[TraceAction]) -> MultiTrace {
+    let mut multitrace = BTreeMap::new();
+    multitrace.insert(lf_ids, trace.to_vec());
+    multitrace
+}
+
+/**
+ * Projects a global trace onto the given sets of lifelines, yielding one component per set.
+ * Actions occurring on lifelines that do not belong to any of the sets are discarded.
+ **/
+pub fn project_global_trace(trace : &[TraceAction], lf_sets : &[BTreeSet<usize>]) -> MultiTrace {
+    let mut multitrace : MultiTrace = lf_sets.iter().map(|lfs| (lfs.clone(), vec![])).collect();
+    for act in trace {
+        if let Some((_,component)) = multitrace.iter_mut().find(|(lfs,_)| lfs.contains(&act.lf_id)) {
+            component.push(*act);
+        }
+    }
+    multitrace
+}
@@ -15,8 +15,13 @@ limitations under the License.
 */
 
 
+use std::collections::BTreeSet;
 use std::hash::Hash;
 
+use crate::core::semantics::trace_action::TraceAction;
+use crate::core::syntax::lang_traits::actions::contains::ContainsTraceActions;
+use crate::core::syntax::lang_traits::involve::involves::InvolvesLifelines;
+
 use super::action::{EmissionAction, ReceptionAction};
 
 
@@ -107,7 +112,7 @@ pub enum Interaction {
     Alt(Box<Interaction>,Box<Interaction>),
     Loop(LoopKind,Box<Interaction>),
     And(Box<Interaction>,Box<Interaction>),
-    //Sync(Vec<TraceAction>,Box<Interaction>,Box<Interaction>)
+    Sync(Vec<TraceAction>,Box<Interaction>,Box<Interaction>)
 }
 
 
@@ -136,12 +141,12 @@ impl Interaction {
             Interaction::Loop(ref lk, ref i1) => {
                 Interaction::Loop(lk.clone(), Box::new(i1.reverse_interaction()))
             },
-            /*Interaction::Sync(ref s,ref i1, ref i2) => {
-                Interaction::Sync(s.clone(),Box::new(i2.reverse()),Box::new(i1.reverse()))
-            },*/
-            _ => {
-                panic!("non-conform interaction");
-            }
+            Interaction::And(ref i1, ref i2) => {
+                Interaction::And(Box::new(i2.reverse_interaction()),Box::new(i1.reverse_interaction()))
+            },
+            Interaction::Sync(ref s,ref i1, ref i2) => {
+                Interaction::Sync(s.clone(),Box::new(i2.reverse_interaction()),Box::new(i1.reverse_interaction()))
+            },
         }
     }
 
@@ -168,15 +173,36 @@ impl Interaction {
             Interaction::Loop(_, _) => {
                 true
             },
-            /*Interaction::Sync(_,ref i1, ref i2) => {
+            Interaction::And(ref i1, ref i2) => {
                 i1.express_empty() && i2.express_empty()
-            },*/
-            _ => {
-                panic!("non-conform interaction");
-            }
+            },
+            Interaction::Sync(_,ref i1, ref i2) => {
+                i1.express_empty() && i2.express_empty()
+            },
         }
     }
 
-}
-
+    /** 
+     * Returns "sync(sync_acts,i1,i2)" as long as at least one of the operands may still express a synchronised action.
+     * Otherwise, the synchronisation is irrelevant and we fall back to the parallel composition of "i1" and "i2".
+     * **/
+    pub fn sync_or_interleave(sync_acts : &[TraceAction], i1 : Interaction, i2 : Interaction) -> Interaction {
+        let sync_acts_as_set : BTreeSet<TraceAction> = sync_acts.iter().cloned().collect();
+        let intersect1 = sync_acts_as_set.intersection(&i1.get_all_trace_actions()).count();
+        let intersect2 = sync_acts_as_set.intersection(&i2.get_all_trace_actions()).count();
+        if intersect1 > 0 || intersect2 > 0 {
+            return Interaction::Sync(sync_acts.to_vec(),Box::new(i1),Box::new(i2));
+        }
+        if i1 == Interaction::Empty {
+            return i2;
+        }
+        if i2 == Interaction::Empty {
+            return i1;
+        }
+        // a coregion on all the lifelines that are shared is equivalent to par
+        let mut par_cr : BTreeSet<usize> = i1.lifelines_that_may_be_involved();
+        par_cr.extend(i2.lifelines_that_may_be_involved());
+        Interaction::CoReg(par_cr.into_iter().collect(),Box::new(i1),Box::new(i2))
+    }
 
+}
@@ -0,0 +1,64 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+
+use std::collections::BTreeSet;
+
+use maplit::btreeset;
+
+use crate::core::semantics::trace_action::{TraceAction, TraceActionKind};
+use crate::core::syntax::interaction::Interaction;
+
+use super::contains::ContainsTraceActions;
+
+
+
+
+
+impl ContainsTraceActions for Interaction {
+    fn get_all_trace_actions(&self) -> BTreeSet<TraceAction> {
+        match self {
+            Interaction::Empty => {
+                btreeset!{}
+            },
+            Interaction::Emission(em_act) => {
+                btreeset!{TraceAction::new(em_act.orig_lf_id, TraceActionKind::Emission, em_act.ms_id)}
+            },
+            Interaction::Reception(rc_act) => {
+                btreeset!{TraceAction::new(rc_act.targ_lf_id, TraceActionKind::Reception, rc_act.ms_id)}
+            },
+            Interaction::Strict(i1, i2) |
+            Interaction::CoReg(_, i1, i2) |
+            Interaction::Alt(i1, i2) |
+            Interaction::Sync(_, i1, i2) => {
+                let mut content = i1.get_all_trace_actions();
+                content.extend( i2.get_all_trace_actions() );
+                content
+            },
+            Interaction::Loop(_, i1) => {
+                i1.get_all_trace_actions()
+            },
+            Interaction::And(i1, i2) => {
+                // every action of the intersection must be an action of both operands
+                let acts1 = i1.get_all_trace_actions();
+                let acts2 = i2.get_all_trace_actions();
+                acts1.intersection(&acts2).cloned().collect()
+            }
+        }
+    }
+}
@@ -44,17 +44,17 @@ impl AvoidsLifelines for Interaction {
             &Interaction::CoReg(_, ref i1, ref i2) => {
                 i1.avoids_all_of(lf_ids) && i2.avoids_all_of(lf_ids)
             },
-            /*&Interaction::Sync(_, ref i1, ref i2) => {
-                return i1.avoids_all_of(lf_ids) && i2.avoids_all_of(lf_ids);
-            },*/
+            &Interaction::Sync(_, ref i1, ref i2) => {
+                i1.avoids_all_of(lf_ids) && i2.avoids_all_of(lf_ids)
+            },
             &Interaction::Alt(ref i1, ref i2) => {
                 i1.avoids_all_of(lf_ids) || i2.avoids_all_of(lf_ids)
             },
             &Interaction::Loop(_, _) => {
                 true
             },
-            &Interaction::And(_,_) => {
-                panic!("non-conform interaction")
+            &Interaction::And(ref i1, ref i2) => {
+                i1.avoids_all_of(lf_ids) && i2.avoids_all_of(lf_ids)
             }
         }
     }
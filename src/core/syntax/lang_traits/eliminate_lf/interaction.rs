@@ -17,6 +17,7 @@ limitations under the License.
 
 use std::collections::BTreeSet;
 
+use crate::core::semantics::trace_action::TraceAction;
 use crate::core::syntax::interaction::Interaction;
 
 use super::eliminable::LifelineEliminable;
@@ -43,39 +44,16 @@ impl LifelineEliminable for Interaction {
                     Interaction::Reception(rc_act.clone())
                 }
             },
-            /*Interaction::Sync(sync_acts,i1,i2) => {
+            Interaction::Sync(sync_acts,i1,i2) => {
                 let new_i1 = i1.eliminate_lifelines(lfs_to_eliminate);
                 let new_i2 = i2.eliminate_lifelines(lfs_to_eliminate);
                 // ***
-                let mut new_sync_acts= vec![];
-                for sync_act in sync_acts {
-                    if !lfs_to_eliminate.contains(&sync_act.lf_id) {
-                        new_sync_acts.push(sync_act.clone());
-                    }
-                }
-                if new_sync_acts.len() > 0 {
-                    return Interaction::Sync(new_sync_acts,
-                                              Box::new(new_i1),
-                                              Box::new(new_i2) );
-                } else {
-                    match &new_i1 {
-                        Interaction::Empty => {
-                            return new_i2;
-                        },
-                        _ => {
-                            match &new_i2 {
-                                Interaction::Empty => {
-                                    return new_i1;
-                                },
-                                _ => {
-                                    return Interaction::Par(Box::new(new_i1),
-                                                            Box::new(new_i2) );
-                                }
-                            }
-                        }
-                    }
-                }
-            },*/
+                let new_sync_acts : Vec<TraceAction> = sync_acts.iter()
+                    .filter(|sync_act| !lfs_to_eliminate.contains(&sync_act.lf_id))
+                    .cloned()
+                    .collect();
+                Interaction::sync_or_interleave(&new_sync_acts, new_i1, new_i2)
+            },
             Interaction::CoReg(cr,i1,i2) => {
                 let new_i1 = i1.eliminate_lifelines(lfs_to_eliminate);
                 let new_i2 = i2.eliminate_lifelines(lfs_to_eliminate);
@@ -157,8 +135,16 @@ impl LifelineEliminable for Interaction {
                     }
                 }
             },
-            Interaction::And(_,_) => {
-                panic!("non-conform interaction");
+            Interaction::And(i1,i2) => {
+                // the projection of an intersection is over-approximated
+                // by the intersection of the projections
+                let new_i1 = i1.eliminate_lifelines(lfs_to_eliminate);
+                let new_i2 = i2.eliminate_lifelines(lfs_to_eliminate);
+                if new_i1 == Interaction::Empty && new_i2 == Interaction::Empty {
+                    Interaction::Empty
+                } else {
+                    Interaction::And(Box::new(new_i1), Box::new(new_i2))
+                }
             }
         }
     }
@@ -51,11 +51,11 @@ impl InvolvesLifelines for Interaction {
                 content.extend( i2.lifelines_that_may_be_involved() );
                 content
             },
-            /*&Interaction::Sync(_, ref i1, ref i2) => {
+            &Interaction::Sync(_, ref i1, ref i2) => {
                 let mut content = i1.lifelines_that_may_be_involved();
                 content.extend( i2.lifelines_that_may_be_involved() );
-                return content;
-            },*/
+                content
+            },
             &Interaction::Alt(ref i1, ref i2) => {
                 let mut content = i1.lifelines_that_may_be_involved();
                 content.extend( i2.lifelines_that_may_be_involved() );
@@ -64,8 +64,11 @@ impl InvolvesLifelines for Interaction {
             &Interaction::Loop(_, i1) => {
                 i1.lifelines_that_may_be_involved()
             },
-            &Interaction::And(_,_) => {
-                panic!("non-conform interaction");
+            &Interaction::And(ref i1, ref i2) => {
+                // every trace of the intersection must be a trace of both operands
+                let may_i1 =  i1.lifelines_that_may_be_involved();
+                let may_i2 =  i2.lifelines_that_may_be_involved();
+                may_i1.intersection(&may_i2).cloned().collect()
             }
         }
     }
@@ -92,11 +95,11 @@ impl InvolvesLifelines for Interaction {
                 content.extend( i2.lifelines_that_may_be_involved() );
                 content
             },
-            /*&Interaction::Sync(_, ref i1, ref i2) => {
-                let mut content = i1.lifelines_that_may_be_involved();
-                content.extend( i2.lifelines_that_may_be_involved() );
-                return content;
-            },*/
+            &Interaction::Sync(_, ref i1, ref i2) => {
+                let mut content = i1.lifelines_that_must_be_involved();
+                content.extend( i2.lifelines_that_must_be_involved() );
+                content
+            },
             &Interaction::Alt(ref i1, ref i2) => {
                 let must_i1 =  i1.lifelines_that_must_be_involved();
                 let must_i2 =  i2.lifelines_that_must_be_involved();
@@ -105,8 +108,10 @@ impl InvolvesLifelines for Interaction {
             &Interaction::Loop(_, _) => {
                 btreeset! {}
             },
-            &Interaction::And(_,_) => {
-                panic!("non-conform interaction");
+            &Interaction::And(ref i1, ref i2) => {
+                let mut content = i1.lifelines_that_must_be_involved();
+                content.extend( i2.lifelines_that_must_be_involved() );
+                content
             }
         }
     }
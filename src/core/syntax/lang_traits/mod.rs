@@ -15,6 +15,7 @@ limitations under the License.
 */
 
 
+pub mod actions;
 pub mod avoid;
 pub mod eliminate_lf;
 pub mod involve;
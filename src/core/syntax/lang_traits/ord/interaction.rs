@@ -144,7 +144,7 @@ impl Ord for Interaction {
             (_,Interaction::Loop(_,_)) => {
                 Ordering::Greater
             },
-            /*(Interaction::Sync(self_acts,self_i1,self_i2),Interaction::Sync(other_acts,other_i1,other_i2)) => {
+            (Interaction::Sync(self_acts,self_i1,self_i2),Interaction::Sync(other_acts,other_i1,other_i2)) => {
                 let max_acts_len = self_acts.len().max(other_acts.len());
                 for i in 0..max_acts_len {
                     match (self_acts.get(i) ,other_acts.get(i) ) {
@@ -169,19 +169,19 @@ impl Ord for Interaction {
                 let cmp_left = self_i1.cmp(other_i1);
                 match &cmp_left {
                     Ordering::Equal => {
-                        return self_i2.cmp(other_i2);
+                        self_i2.cmp(other_i2)
                     },
                     _ => {
-                        return cmp_left;
+                        cmp_left
                     }
                 }
             },
             (Interaction::Sync(_,_,_),_) => {
-                return Ordering::Less;
+                Ordering::Less
             },
             (_,Interaction::Sync(_,_,_)) => {
-                return Ordering::Greater;
-            },*/
+                Ordering::Greater
+            },
             (Interaction::And(self_i1,self_i2),Interaction::And(other_i1,other_i2)) => {
                 let cmp_left = self_i1.cmp(other_i1);
                 match &cmp_left {
@@ -51,35 +51,11 @@ impl LifelinePrunable for Interaction {
                     }
                 }
             },
-            /*Interaction::Sync(sync_acts, i1, i2) => {
+            Interaction::Sync(sync_acts, i1, i2) => {
                 let pruned_i1 = i1.prune(lf_ids);
                 let pruned_i2 = i2.prune(lf_ids);
-                // ***
-                let acts1 = pruned_i1.get_all_trace_actions();
-                let acts2 = pruned_i2.get_all_trace_actions();
-                // ***
-                let sync_acts_as_set : BTreeSet<TraceAction> = BTreeSet::from_iter(sync_acts.iter().cloned());
-                let intersetc1 = sync_acts_as_set.intersection(&acts1).count();
-                let intersetc2 = sync_acts_as_set.intersection(&acts2).count();
-                // ***
-                let new_i : Interaction;
-                if intersetc1 == 0 && intersetc2 == 0 {
-                    if pruned_i1 == Interaction::Empty {
-                        return pruned_i2;
-                    } else {
-                        if pruned_i2 == Interaction::Empty {
-                            return pruned_i1;
-                        } else {
-                            return Interaction::Par( Box::new(pruned_i1) ,
-                                                     Box::new(pruned_i2) );
-                        }
-                    }
-                } else {
-                    return Interaction::Sync(sync_acts.clone(),
-                                             Box::new(pruned_i1) ,
-                                             Box::new(pruned_i2));
-                }
-            },*/
+                Interaction::sync_or_interleave(sync_acts, pruned_i1, pruned_i2)
+            },
             Interaction::Strict(i1, i2) => {
                 let pruned_i1 = i1.prune(lf_ids);
                 let pruned_i2 = i2.prune(lf_ids);
@@ -129,8 +105,14 @@ impl LifelinePrunable for Interaction {
                 }
                 return Interaction::Empty;
             },
-            Interaction::And(_,_) => {
-                panic!("non-conform interaction");
+            Interaction::And(i1, i2) => {
+                let pruned_i1 = i1.prune(lf_ids);
+                let pruned_i2 = i2.prune(lf_ids);
+                if pruned_i1 == Interaction::Empty && pruned_i2 == Interaction::Empty {
+                    Interaction::Empty
+                } else {
+                    Interaction::And( Box::new(pruned_i1) , Box::new(pruned_i2) )
+                }
             }
         }
     }
@@ -160,6 +142,12 @@ impl LifelinePrunable for Interaction {
                     }
                 }
             },
+            Interaction::Sync(sync_acts, i1, i2) => {
+                let (pruned_i1,mut aff1) = i1.prune_with_affected(lf_ids);
+                let (pruned_i2,aff2) = i2.prune_with_affected(lf_ids);
+                aff1.extend(aff2);
+                (Interaction::sync_or_interleave(sync_acts, pruned_i1, pruned_i2),aff1)
+            },
             Interaction::Strict(i1, i2) => {
                 let (pruned_i1,mut aff1) = i1.prune_with_affected(lf_ids);
                 let (pruned_i2,aff2) = i2.prune_with_affected(lf_ids);
@@ -205,8 +193,15 @@ impl LifelinePrunable for Interaction {
                 }
                 return (Interaction::Empty,i1.lifelines_that_may_be_involved());
             },
-            Interaction::And(_,_) => {
-                panic!("non-conform interaction");
+            Interaction::And(i1, i2) => {
+                let (pruned_i1,mut aff1) = i1.prune_with_affected(lf_ids);
+                let (pruned_i2,aff2) = i2.prune_with_affected(lf_ids);
+                aff1.extend(aff2);
+                if pruned_i1 == Interaction::Empty && pruned_i2 == Interaction::Empty {
+                    (Interaction::Empty,aff1)
+                } else {
+                    (Interaction::And( Box::new(pruned_i1) , Box::new(pruned_i2) ),aff1)
+                }
             }
         }
     }
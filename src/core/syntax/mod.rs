@@ -17,4 +17,5 @@ limitations under the License.
 
 pub mod action;
 pub mod interaction;
-pub mod lang_traits;
\ No newline at end of file
+pub mod lang_traits;
+pub mod validation;
@@ -0,0 +1,215 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+
+use std::collections::BTreeSet;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::semantics::position::Position;
+use crate::core::semantics::trace_action::TraceAction;
+use crate::core::syntax::interaction::{Interaction, LoopKind};
+use crate::core::syntax::lang_traits::actions::contains::ContainsTraceActions;
+
+
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum DiagnosticSeverity {
+    // the interaction refers to elements which do not exist, it must not be given to the semantics
+    Error,
+    // the interaction is usable but likely not what was intended or not in normal form
+    Warning
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum DiagnosticKind {
+    UnknownLifeline(usize),
+    UnknownMessage(usize),
+    UnknownGate(usize),
+    // the lifelines of a coregion (of a CoReg operator or of a loopC) are not sorted
+    UnsortedCoregion(Vec<usize>),
+    // a lifeline occurs several times in a coregion
+    DuplicateLifelineInCoregion(usize),
+    // an action occurs several times in the synchronisation set of a Sync operator
+    DuplicateSynchronisationAction(TraceAction),
+    // an action of an operand of an And can never be executed because it does not occur in the other operand
+    DeadActionUnderAnd(TraceAction)
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Diagnostic {
+    pub severity : DiagnosticSeverity,
+    pub kind : DiagnosticKind,
+    // position of the sub-interaction at which the issue occurs
+    pub position : Position
+}
+
+impl Diagnostic {
+    pub fn new(severity : DiagnosticSeverity, kind : DiagnosticKind, position : Position) -> Diagnostic {
+        Diagnostic{severity,kind,position}
+    }
+    pub fn is_error(&self) -> bool {
+        self.severity == DiagnosticSeverity::Error
+    }
+}
+
+
+
+/**
+ * Checks that an interaction is well-formed w.r.t. a context.
+ * Diagnostics with the *Error* severity indicate that the interaction refers to lifelines,
+ * messages or gates that are not declared in the context while *Warnings* point to
+ * coregions and synchronisation sets that are not normalized and to intersections
+ * with actions that can never be executed.
+ * Diagnostics are returned in prefix order of the positions at which they occur.
+ **/
+pub fn validate(
+    interaction : &Interaction,
+    ctx : &GeneralContext
+) -> Vec<Diagnostic> {
+    let mut validator = InteractionValidator{ctx,path:vec![],diagnostics:vec![]};
+    validator.validate_rec(interaction);
+    validator.diagnostics
+}
+
+pub fn has_errors(diagnostics : &[Diagnostic]) -> bool {
+    diagnostics.iter().any(|d| d.is_error())
+}
+
+
+
+struct InteractionValidator<'a> {
+    ctx : &'a GeneralContext,
+    // whether we went left (true) or right (false) from the root
+    path : Vec<bool>,
+    diagnostics : Vec<Diagnostic>
+}
+
+impl<'a> InteractionValidator<'a> {
+
+    fn current_position(&self) -> Position {
+        let mut position = Position::Epsilon;
+        for went_left in self.path.iter().rev() {
+            if *went_left {
+                position = Position::Left(Box::new(position));
+            } else {
+                position = Position::Right(Box::new(position));
+            }
+        }
+        position
+    }
+
+    fn report(&mut self, severity : DiagnosticSeverity, kind : DiagnosticKind) {
+        let position = self.current_position();
+        self.diagnostics.push(Diagnostic::new(severity,kind,position));
+    }
+
+    fn check_lifeline(&mut self, lf_id : usize) {
+        if lf_id >= self.ctx.get_lf_num() {
+            self.report(DiagnosticSeverity::Error,DiagnosticKind::UnknownLifeline(lf_id));
+        }
+    }
+
+    fn check_message(&mut self, ms_id : usize) {
+        if ms_id >= self.ctx.get_ms_num() {
+            self.report(DiagnosticSeverity::Error,DiagnosticKind::UnknownMessage(ms_id));
+        }
+    }
+
+    fn check_gate(&mut self, gt_id : usize) {
+        if gt_id >= self.ctx.get_gt_num() {
+            self.report(DiagnosticSeverity::Error,DiagnosticKind::UnknownGate(gt_id));
+        }
+    }
+
+    fn check_coregion(&mut self, cr : &[usize]) {
+        let mut seen = BTreeSet::new();
+        for lf_id in cr {
+            self.check_lifeline(*lf_id);
+            if !seen.insert(*lf_id) {
+                self.report(DiagnosticSeverity::Warning,DiagnosticKind::DuplicateLifelineInCoregion(*lf_id));
+            }
+        }
+        if cr.windows(2).any(|w| w[0] > w[1]) {
+            self.report(DiagnosticSeverity::Warning,DiagnosticKind::UnsortedCoregion(cr.to_vec()));
+        }
+    }
+
+    fn validate_left_and_right(&mut self, i1 : &Interaction, i2 : &Interaction) {
+        self.path.push(true);
+        self.validate_rec(i1);
+        self.path.pop();
+        self.path.push(false);
+        self.validate_rec(i2);
+        self.path.pop();
+    }
+
+    fn validate_rec(&mut self, interaction : &Interaction) {
+        match interaction {
+            Interaction::Empty => {},
+            Interaction::Emission(em_act) => {
+                self.check_lifeline(em_act.orig_lf_id);
+                self.check_message(em_act.ms_id);
+                for gt_id in &em_act.target_gates {
+                    self.check_gate(*gt_id);
+                }
+            },
+            Interaction::Reception(rc_act) => {
+                if let Some(gt_id) = rc_act.origin_gate {
+                    self.check_gate(gt_id);
+                }
+                self.check_message(rc_act.ms_id);
+                self.check_lifeline(rc_act.targ_lf_id);
+            },
+            Interaction::Strict(i1,i2) |
+            Interaction::Alt(i1,i2) => {
+                self.validate_left_and_right(i1,i2);
+            },
+            Interaction::CoReg(cr,i1,i2) => {
+                self.check_coregion(cr);
+                self.validate_left_and_right(i1,i2);
+            },
+            Interaction::Loop(lk,i1) => {
+                if let LoopKind::Coreg(cr) = lk {
+                    self.check_coregion(cr);
+                }
+                self.path.push(true);
+                self.validate_rec(i1);
+                self.path.pop();
+            },
+            Interaction::And(i1,i2) => {
+                let acts1 = i1.get_all_trace_actions();
+                let acts2 = i2.get_all_trace_actions();
+                for act in acts1.symmetric_difference(&acts2) {
+                    self.report(DiagnosticSeverity::Warning,DiagnosticKind::DeadActionUnderAnd(*act));
+                }
+                self.validate_left_and_right(i1,i2);
+            },
+            Interaction::Sync(sync_acts,i1,i2) => {
+                let mut seen = BTreeSet::new();
+                for sync_act in sync_acts {
+                    self.check_lifeline(sync_act.lf_id);
+                    self.check_message(sync_act.ms_id);
+                    if !seen.insert(*sync_act) {
+                        self.report(DiagnosticSeverity::Warning,DiagnosticKind::DuplicateSynchronisationAction(*sync_act));
+                    }
+                }
+                self.validate_left_and_right(i1,i2);
+            }
+        }
+    }
+}
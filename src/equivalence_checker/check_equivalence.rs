@@ -0,0 +1,129 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::general_context::GeneralContext;
+use crate::core::semantics::trace_action::TraceAction;
+use crate::core::syntax::interaction::Interaction;
+use crate::inclusion_checker::check_inclusion::{check_inclusion_of_interactions_with_options, InteractionInclusionGlobalVerdict};
+use crate::inclusion_checker::options::InteractionInclusionCheckingOptions;
+use crate::interfaces::HibouGraphvizLoggerParam;
+
+
+
+/**
+ * The direction in which inclusion does not hold.
+ **/
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum InclusionDirection {
+    // the left interaction has a behaviour that the right one does not have
+    LeftNotIncludedInRight,
+    // the right interaction has a behaviour that the left one does not have
+    RightNotIncludedInLeft
+}
+
+impl std::fmt::Display for InclusionDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InclusionDirection::LeftNotIncludedInRight => {
+                write!(f, "LeftNotIncludedInRight")
+            },
+            InclusionDirection::RightNotIncludedInLeft => {
+                write!(f, "RightNotIncludedInLeft")
+            },
+        }
+    }
+}
+
+
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum InteractionEquivalenceGlobalVerdict {
+    Equivalent,
+    // with the direction that failed and a distinguishing trace
+    NotEquivalent(InclusionDirection,Vec<TraceAction>),
+    EquivalentUpToExploredSemantics
+}
+
+impl std::fmt::Display for InteractionEquivalenceGlobalVerdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InteractionEquivalenceGlobalVerdict::Equivalent => {
+                write!(f, "Equivalent")
+            },
+            InteractionEquivalenceGlobalVerdict::NotEquivalent(direction,_) => {
+                write!(f, "NotEquivalent({})", direction)
+            },
+            InteractionEquivalenceGlobalVerdict::EquivalentUpToExploredSemantics => {
+                write!(f, "EquivalentUpToExploredSemantics")
+            },
+        }
+    }
+}
+
+
+
+pub fn check_equivalence_of_interactions(
+    left : &Interaction,
+    right : &Interaction,
+    graphviz_param : Option<(&GeneralContext,&str,&str,&HibouGraphvizLoggerParam)>
+) -> InteractionEquivalenceGlobalVerdict {
+    check_equivalence_of_interactions_with_options(
+        left,
+        right,
+        &InteractionInclusionCheckingOptions::default(),
+        graphviz_param
+    )
+}
+
+/**
+ * Checks inclusion in both directions.
+ * The second direction is not explored if the first one already distinguishes the interactions.
+ **/
+pub fn check_equivalence_of_interactions_with_options(
+    left : &Interaction,
+    right : &Interaction,
+    options : &InteractionInclusionCheckingOptions,
+    graphviz_param : Option<(&GeneralContext,&str,&str,&HibouGraphvizLoggerParam)>
+) -> InteractionEquivalenceGlobalVerdict {
+    let left_in_right = check_inclusion_of_interactions_with_options(
+        left,
+        right,
+        options,
+        graphviz_param
+    );
+    if let InteractionInclusionGlobalVerdict::IsNotIncluded(trace) = left_in_right {
+        return InteractionEquivalenceGlobalVerdict::NotEquivalent(InclusionDirection::LeftNotIncludedInRight, trace);
+    }
+    // ***
+    let right_in_left = check_inclusion_of_interactions_with_options(
+        right,
+        left,
+        options,
+        graphviz_param.map(|(gen_ctx,fname1,fname2,gv_log_param)| (gen_ctx,fname2,fname1,gv_log_param))
+    );
+    match (left_in_right,right_in_left) {
+        (_,InteractionInclusionGlobalVerdict::IsNotIncluded(trace)) => {
+            InteractionEquivalenceGlobalVerdict::NotEquivalent(InclusionDirection::RightNotIncludedInLeft, trace)
+        },
+        (InteractionInclusionGlobalVerdict::IsIncluded,InteractionInclusionGlobalVerdict::IsIncluded) => {
+            InteractionEquivalenceGlobalVerdict::Equivalent
+        },
+        _ => {
+            InteractionEquivalenceGlobalVerdict::EquivalentUpToExploredSemantics
+        }
+    }
+}
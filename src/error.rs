@@ -0,0 +1,87 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+
+use std::fmt;
+
+use crate::core::semantics::position::Position;
+use crate::seqdiag_lib_interface::io::InteractionParsingError;
+
+
+
+/**
+ * Errors surfaced by the fallible (*try_*) variants of the public API,
+ * e.g. when parsing malformed input, when executing an interaction at a position
+ * which does not exist in it, or when drawing an interaction which refers
+ * to elements that are not declared in the context.
+ **/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum HibouError {
+    // the input text could not be parsed
+    Parsing(Box<InteractionParsingError>),
+    // the input text was parsed but describes a pattern that has no interaction counterpart
+    UnsupportedPattern(String),
+    // the position does not designate an action or a compatible operator in the interaction
+    InvalidPosition(Position),
+    UnknownLifeline(usize),
+    UnknownMessage(usize),
+    UnknownGate(usize),
+    // reading or writing a file failed
+    Io(String),
+    // a configuration parameter is outside of its domain e.g. a probability outside of [0,1]
+    InvalidConfiguration(String)
+}
+
+impl fmt::Display for HibouError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HibouError::Parsing(parsing_error) => {
+                write!(f, "parsing error: {}", parsing_error)
+            },
+            HibouError::UnsupportedPattern(msg) => {
+                write!(f, "unsupported pattern: {}", msg)
+            },
+            HibouError::InvalidPosition(pos) => {
+                write!(f, "invalid position: {:?}", pos)
+            },
+            HibouError::UnknownLifeline(lf_id) => {
+                write!(f, "unknown lifeline id {}", lf_id)
+            },
+            HibouError::UnknownMessage(ms_id) => {
+                write!(f, "unknown message id {}", ms_id)
+            },
+            HibouError::UnknownGate(gt_id) => {
+                write!(f, "unknown gate id {}", gt_id)
+            },
+            HibouError::Io(msg) => {
+                write!(f, "io error: {}", msg)
+            },
+            HibouError::InvalidConfiguration(msg) => {
+                write!(f, "invalid configuration: {}", msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HibouError {}
+
+impl From<std::io::Error> for HibouError {
+    fn from(e : std::io::Error) -> Self {
+        HibouError::Io(e.to_string())
+    }
+}
@@ -0,0 +1,146 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashMap;
+
+use crate::core::syntax::interaction::Interaction;
+use crate::error::HibouError;
+
+
+
+fn check_probability(name : &str, probability : f64) -> Result<(),HibouError> {
+    if (0.0..=1.0).contains(&probability) {
+        Ok(())
+    } else {
+        Err(HibouError::InvalidConfiguration(format!("{} must be between 0 and 1, got {}", name, probability)))
+    }
+}
+
+
+
+/**
+ * Relative weights with which operators are drawn when generating random interactions.
+ * A weight of 0 disables the corresponding operator.
+ **/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InteractionGenerationOperatorWeights {
+    pub strict : u32,
+    // weak sequencing i.e. a CoReg with no concurrent lifeline
+    pub seq : u32,
+    // a CoReg over a random non-empty set of lifelines
+    pub coreg : u32,
+    pub alt : u32,
+    pub loop_strict : u32,
+    pub loop_head_first : u32,
+    pub loop_weak : u32,
+    // a loopC over a random non-empty set of lifelines
+    pub loop_coreg : u32,
+    pub and : u32,
+    pub sync : u32
+}
+
+impl Default for InteractionGenerationOperatorWeights {
+    // And and Sync are disabled by default given that they easily yield actions that can never be executed
+    fn default() -> Self {
+        Self {
+            strict : 2,
+            seq : 3,
+            coreg : 1,
+            alt : 3,
+            loop_strict : 1,
+            loop_head_first : 1,
+            loop_weak : 1,
+            loop_coreg : 1,
+            and : 0,
+            sync : 0
+        }
+    }
+}
+
+
+
+/**
+ * Configuration of the generation of random interactions.
+ * The probabilities must be between 0 and 1, which is checked by *new*.
+ **/
+#[derive(Clone, PartialEq, Debug)]
+pub struct InteractionGenerationConfig {
+    // maximum number of nested operators
+    pub max_depth : u32,
+    // probability to stop at a leaf before reaching the maximum depth
+    pub leaf_probability : f64,
+    // probability for a leaf to be an action (or a message passing) rather than the empty interaction
+    pub action_density : f64,
+    pub operator_weights : InteractionGenerationOperatorWeights
+}
+
+impl InteractionGenerationConfig {
+    pub fn new(
+        max_depth : u32,
+        leaf_probability : f64,
+        action_density : f64,
+        operator_weights : InteractionGenerationOperatorWeights
+    ) -> Result<Self,HibouError> {
+        check_probability("leaf_probability", leaf_probability)?;
+        check_probability("action_density", action_density)?;
+        Ok(Self { max_depth, leaf_probability, action_density, operator_weights })
+    }
+}
+
+impl Default for InteractionGenerationConfig {
+    fn default() -> Self {
+        Self::new(4, 0.2, 0.9, InteractionGenerationOperatorWeights::default()).unwrap()
+    }
+}
+
+
+
+/**
+ * Configuration of the random simulation of executions of an interaction.
+ * The loop continuation probability must be between 0 and 1, which is checked by *new*.
+ **/
+#[derive(Clone, PartialEq, Debug)]
+pub struct TraceSimulationConfig {
+    // probability to keep executing actions once the interaction may terminate (e.g. to perform another loop iteration)
+    pub loop_continuation_probability : f64,
+    // relative weights of alternative branches, given by their sub-interaction
+    // branches which are not listed have a weight of 1 and branches with a weight of 0 are never taken
+    pub alt_branch_weights : HashMap<Interaction,u32>,
+    // maximum number of actions in a simulated trace
+    pub max_length : Option<u32>
+}
+
+impl TraceSimulationConfig {
+    pub fn new(
+        loop_continuation_probability : f64,
+        alt_branch_weights : HashMap<Interaction,u32>,
+        max_length : Option<u32>
+    ) -> Result<Self,HibouError> {
+        check_probability("loop_continuation_probability", loop_continuation_probability)?;
+        Ok(Self { loop_continuation_probability, alt_branch_weights, max_length })
+    }
+
+    pub fn get_alt_branch_weight(&self, branch : &Interaction) -> u32 {
+        *self.alt_branch_weights.get(branch).unwrap_or(&1)
+    }
+}
+
+impl Default for TraceSimulationConfig {
+    fn default() -> Self {
+        Self::new(0.5, HashMap::new(), Some(100)).unwrap()
+    }
+}
@@ -0,0 +1,220 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+
+use rand::distributions::WeightedIndex;
+use rand::prelude::Distribution;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::core::general_context::GeneralContext;
+use crate::core::semantics::trace_action::{TraceAction, TraceActionKind};
+use crate::core::syntax::action::{EmissionAction, ReceptionAction};
+use crate::core::syntax::interaction::{Interaction, LoopKind};
+
+use super::conf::{InteractionGenerationConfig, InteractionGenerationOperatorWeights};
+
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(in crate::generation) enum GeneratedOperator {
+    Strict,
+    Seq,
+    CoReg,
+    Alt,
+    LoopStrict,
+    LoopHeadFirst,
+    LoopWeak,
+    LoopCoReg,
+    And,
+    Sync
+}
+
+impl InteractionGenerationOperatorWeights {
+    // operators with a non-zero weight
+    pub(in crate::generation) fn get_weighted_operators(&self) -> Vec<(GeneratedOperator,u32)> {
+        vec![
+            (GeneratedOperator::Strict,self.strict),
+            (GeneratedOperator::Seq,self.seq),
+            (GeneratedOperator::CoReg,self.coreg),
+            (GeneratedOperator::Alt,self.alt),
+            (GeneratedOperator::LoopStrict,self.loop_strict),
+            (GeneratedOperator::LoopHeadFirst,self.loop_head_first),
+            (GeneratedOperator::LoopWeak,self.loop_weak),
+            (GeneratedOperator::LoopCoReg,self.loop_coreg),
+            (GeneratedOperator::And,self.and),
+            (GeneratedOperator::Sync,self.sync)
+        ].into_iter().filter(|(_,weight)| *weight > 0).collect()
+    }
+}
+
+
+
+/**
+ * Generates a random interaction which is well-formed w.r.t. the context
+ * i.e. which only refers to declared lifelines, messages and gates and in which coregions are sorted.
+ * If the context declares no lifeline or no message, the empty interaction is returned.
+ **/
+pub fn generate_interaction<R : Rng>(
+    ctx : &GeneralContext,
+    config : &InteractionGenerationConfig,
+    rng : &mut R
+) -> Interaction {
+    let operators = config.operator_weights.get_weighted_operators();
+    let distribution = if operators.is_empty() {
+        None
+    } else {
+        Some(WeightedIndex::new(operators.iter().map(|(_,weight)| *weight)).unwrap())
+    };
+    let generator = InteractionGenerator{ctx,config,operators,distribution};
+    generator.generate(rng,config.max_depth)
+}
+
+/**
+ * Reproducible variant of *generate_interaction*.
+ **/
+pub fn generate_interaction_from_seed(
+    ctx : &GeneralContext,
+    config : &InteractionGenerationConfig,
+    seed : u64
+) -> Interaction {
+    generate_interaction(ctx,config,&mut StdRng::seed_from_u64(seed))
+}
+
+
+
+struct InteractionGenerator<'a> {
+    ctx : &'a GeneralContext,
+    config : &'a InteractionGenerationConfig,
+    operators : Vec<(GeneratedOperator,u32)>,
+    distribution : Option<WeightedIndex<u32>>
+}
+
+impl<'a> InteractionGenerator<'a> {
+
+    fn generate<R : Rng>(&self, rng : &mut R, depth : u32) -> Interaction {
+        match &self.distribution {
+            Some(distribution) if depth > 0 && !rng.gen_bool(self.config.leaf_probability) => {
+                let (operator,_) = self.operators[distribution.sample(rng)];
+                self.generate_under_operator(rng, operator, depth - 1)
+            },
+            _ => {
+                self.generate_leaf(rng)
+            }
+        }
+    }
+
+    fn generate_under_operator<R : Rng>(&self, rng : &mut R, operator : GeneratedOperator, depth : u32) -> Interaction {
+        let sub = |rng : &mut R| Box::new(self.generate(rng, depth));
+        match operator {
+            GeneratedOperator::Strict => {
+                Interaction::Strict(sub(rng), sub(rng))
+            },
+            GeneratedOperator::Seq => {
+                Interaction::CoReg(vec![], sub(rng), sub(rng))
+            },
+            GeneratedOperator::CoReg => {
+                let cr = self.generate_coregion(rng);
+                Interaction::CoReg(cr, sub(rng), sub(rng))
+            },
+            GeneratedOperator::Alt => {
+                Interaction::Alt(sub(rng), sub(rng))
+            },
+            GeneratedOperator::LoopStrict => {
+                Interaction::Loop(LoopKind::SStrictSeq, sub(rng))
+            },
+            GeneratedOperator::LoopHeadFirst => {
+                Interaction::Loop(LoopKind::HHeadFirstWS, sub(rng))
+            },
+            GeneratedOperator::LoopWeak => {
+                Interaction::Loop(LoopKind::Coreg(vec![]), sub(rng))
+            },
+            GeneratedOperator::LoopCoReg => {
+                let cr = self.generate_coregion(rng);
+                Interaction::Loop(LoopKind::Coreg(cr), sub(rng))
+            },
+            GeneratedOperator::And => {
+                Interaction::And(sub(rng), sub(rng))
+            },
+            GeneratedOperator::Sync => {
+                let sync_acts = self.generate_synchronisation_actions(rng);
+                Interaction::Sync(sync_acts, sub(rng), sub(rng))
+            }
+        }
+    }
+
+    // a sorted non-empty set of lifelines
+    fn generate_coregion<R : Rng>(&self, rng : &mut R) -> Vec<usize> {
+        let lf_num = self.ctx.get_lf_num();
+        if lf_num == 0 {
+            return vec![];
+        }
+        let mut cr : Vec<usize> = (0..lf_num).filter(|_| rng.gen_bool(0.5)).collect();
+        if cr.is_empty() {
+            cr.push(rng.gen_range(0..lf_num));
+        }
+        cr
+    }
+
+    // a sorted set of one or two distinct actions
+    fn generate_synchronisation_actions<R : Rng>(&self, rng : &mut R) -> Vec<TraceAction> {
+        let (lf_num,ms_num) = (self.ctx.get_lf_num(),self.ctx.get_ms_num());
+        if lf_num == 0 || ms_num == 0 {
+            return vec![];
+        }
+        let mut sync_acts = vec![];
+        for _ in 0..rng.gen_range(1..=2) {
+            let act_kind = if rng.gen_bool(0.5) {TraceActionKind::Emission} else {TraceActionKind::Reception};
+            let act = TraceAction::new(rng.gen_range(0..lf_num), act_kind, rng.gen_range(0..ms_num));
+            if !sync_acts.contains(&act) {
+                sync_acts.push(act);
+            }
+        }
+        sync_acts.sort();
+        sync_acts
+    }
+
+    fn generate_leaf<R : Rng>(&self, rng : &mut R) -> Interaction {
+        let (lf_num,ms_num,gt_num) = (self.ctx.get_lf_num(),self.ctx.get_ms_num(),self.ctx.get_gt_num());
+        if lf_num == 0 || ms_num == 0 || !rng.gen_bool(self.config.action_density) {
+            return Interaction::Empty;
+        }
+        let ms_id = rng.gen_range(0..ms_num);
+        let lf_id = rng.gen_range(0..lf_num);
+        let gate = if gt_num > 0 && rng.gen_bool(0.25) {Some(rng.gen_range(0..gt_num))} else {None};
+        match rng.gen_range(0..4) {
+            0 => {
+                Interaction::Emission(EmissionAction::new(lf_id, ms_id, gate.into_iter().collect()))
+            },
+            1 => {
+                Interaction::Reception(ReceptionAction::new(gate, ms_id, lf_id))
+            },
+            _ => {
+                // message passing between two lifelines, which are distinct whenever possible
+                let mut targ_lf_id = rng.gen_range(0..lf_num);
+                if lf_num > 1 && targ_lf_id == lf_id {
+                    targ_lf_id = (targ_lf_id + 1) % lf_num;
+                }
+                Interaction::Strict(
+                    Box::new(Interaction::Emission(EmissionAction::new(lf_id, ms_id, vec![]))),
+                    Box::new(Interaction::Reception(ReceptionAction::new(None, ms_id, targ_lf_id)))
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,196 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use rand::distributions::WeightedIndex;
+use rand::prelude::Distribution;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::core::general_context::GeneralContext;
+use crate::core::semantics::execute::execute_interaction;
+use crate::core::semantics::frontier::global_frontier;
+use crate::core::semantics::multitrace::{project_global_trace, MultiTrace};
+use crate::core::semantics::position::Position;
+use crate::core::semantics::trace_action::TraceAction;
+use crate::core::syntax::interaction::Interaction;
+
+use super::conf::TraceSimulationConfig;
+
+
+
+/**
+ * The result of a random simulation.
+ * The trace may not be accepted by the interaction if the simulation has been interrupted
+ * either because the maximum length has been reached or because no action could be executed.
+ **/
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct SimulatedTrace {
+    pub trace : Vec<TraceAction>,
+    pub is_accepted : bool
+}
+
+impl SimulatedTrace {
+
+    pub fn new(trace : Vec<TraceAction>, is_accepted : bool) -> Self {
+        Self { trace, is_accepted }
+    }
+
+    /**
+     * Splits the trace into a multi-trace with one component per lifeline of the context.
+     **/
+    pub fn as_multitrace(&self, ctx : &GeneralContext) -> MultiTrace {
+        let lf_sets : Vec<BTreeSet<usize>> = ctx.get_all_lfs_ids().into_iter().map(
+            |lf_id| BTreeSet::from([lf_id])
+        ).collect();
+        project_global_trace(&self.trace, &lf_sets)
+    }
+
+}
+
+
+
+/**
+ * Randomly walks the semantics of the interaction, executing one frontier element at a time.
+ * Whenever the interaction may terminate, the simulation continues with the loop continuation probability.
+ * When an alternative is resolved, each branch is taken with a probability proportional to its weight.
+ * Otherwise, the executed action is drawn uniformly among the immediately executable actions.
+ **/
+pub fn simulate_trace<R : Rng>(
+    int : &Interaction,
+    config : &TraceSimulationConfig,
+    rng : &mut R
+) -> SimulatedTrace {
+    let mut current = int.clone();
+    let mut trace = vec![];
+    loop {
+        if current.express_empty() && !rng.gen_bool(config.loop_continuation_probability) {
+            break;
+        }
+        if config.max_length.is_some_and(|max_length| trace.len() as u32 >= max_length) {
+            break;
+        }
+        let frontier = global_frontier(&current, false);
+        let candidates = frontier.iter().enumerate().map(|(frt_id,frt_elt)| (frt_id,&frt_elt.position)).collect();
+        let Some(chosen) = choose_frontier_element(&current, candidates, config, rng) else {
+            break;
+        };
+        let frt_elt = frontier.get(chosen).unwrap();
+        trace.extend(frt_elt.target_actions.iter().copied());
+        current = execute_interaction(
+            &current,
+            &frt_elt.position,
+            &frt_elt.target_lf_ids,
+            false
+        ).interaction;
+    }
+    let is_accepted = current.express_empty();
+    SimulatedTrace::new(trace, is_accepted)
+}
+
+pub fn simulate_trace_from_seed(
+    int : &Interaction,
+    config : &TraceSimulationConfig,
+    seed : u64
+) -> SimulatedTrace {
+    simulate_trace(int, config, &mut StdRng::seed_from_u64(seed))
+}
+
+
+
+// frontier elements given by their index in the frontier and by their position relative to a sub-interaction
+type FrontierCandidates<'a> = Vec<(usize,&'a Position)>;
+
+/**
+ * Chooses one of the candidate frontier elements, which positions are relative to "int".
+ * The candidates are grouped according to the operand of "int" in which they are.
+ * Under an alternative, the group is drawn according to the weights of the branches.
+ * Otherwise, it is drawn proportionally to its number of candidates.
+ * Returns None if all the candidates are in branches with a weight of 0.
+ **/
+fn choose_frontier_element<R : Rng>(
+    int : &Interaction,
+    candidates : FrontierCandidates,
+    config : &TraceSimulationConfig,
+    rng : &mut R
+) -> Option<usize> {
+    let mut on_left = vec![];
+    let mut on_right = vec![];
+    let mut elsewhere = vec![];
+    for (frt_id,position) in candidates {
+        match position {
+            Position::Left(sub_pos) => {
+                on_left.push((frt_id,&**sub_pos));
+            },
+            Position::Right(sub_pos) => {
+                on_right.push((frt_id,&**sub_pos));
+            },
+            _ => {
+                elsewhere.push(frt_id);
+            }
+        }
+    }
+    let (left_int,right_int) = match int {
+        Interaction::Strict(i1,i2) |
+        Interaction::CoReg(_,i1,i2) |
+        Interaction::Alt(i1,i2) |
+        Interaction::And(i1,i2) |
+        Interaction::Sync(_,i1,i2) => {
+            (Some(&**i1),Some(&**i2))
+        },
+        Interaction::Loop(_,i1) => {
+            (Some(&**i1),None)
+        },
+        _ => {
+            (None,None)
+        }
+    };
+    let (left_weight,right_weight) = match int {
+        Interaction::Alt(i1,i2) => {
+            (config.get_alt_branch_weight(i1),config.get_alt_branch_weight(i2))
+        },
+        _ => {
+            (on_left.len() as u32,on_right.len() as u32)
+        }
+    };
+    let mut groups : Vec<(u32,Option<&Interaction>,FrontierCandidates)> = vec![];
+    if !on_left.is_empty() {
+        groups.push((left_weight,left_int,on_left));
+    }
+    if !on_right.is_empty() {
+        groups.push((right_weight,right_int,on_right));
+    }
+    let elsewhere_weight = elsewhere.len() as u32;
+    // a group from which no candidate can be chosen is discarded and another one is drawn
+    loop {
+        let weights : Vec<u32> = groups.iter().map(|(weight,_,_)| *weight)
+            .chain(std::iter::once(elsewhere_weight))
+            .collect();
+        let Ok(distribution) = WeightedIndex::new(&weights) else {
+            return None;
+        };
+        let group_id = distribution.sample(rng);
+        if group_id == groups.len() {
+            return Some(elsewhere[rng.gen_range(0..elsewhere.len())]);
+        }
+        let (_,sub_int,sub_candidates) = groups.remove(group_id);
+        if let Some(chosen) = choose_frontier_element(sub_int.unwrap(), sub_candidates, config, rng) {
+            return Some(chosen);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+
+use proptest::collection::btree_set;
+use proptest::prelude::*;
+use proptest::strategy::Union;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::semantics::trace_action::{TraceAction, TraceActionKind};
+use crate::core::syntax::action::{EmissionAction, ReceptionAction};
+use crate::core::syntax::interaction::{Interaction, LoopKind};
+
+use super::conf::InteractionGenerationConfig;
+use super::generate::GeneratedOperator;
+
+
+
+// weights are given to proptest as integers
+const DENSITY_PRECISION : f64 = 100.0;
+
+/**
+ * Proptest strategy generating interactions which are well-formed w.r.t. the context.
+ * The depth is bounded by *max_depth* and operators are drawn according to the configured weights.
+ * Contrary to *generate_interaction*, the *leaf_probability* is not used, given that
+ * proptest itself decides when to stop recursing, but generated interactions can be shrunk.
+ **/
+pub fn interaction_strategy(
+    ctx : &GeneralContext,
+    config : &InteractionGenerationConfig
+) -> BoxedStrategy<Interaction> {
+    let (lf_num,ms_num,gt_num) = (ctx.get_lf_num(),ctx.get_ms_num(),ctx.get_gt_num());
+    if lf_num == 0 || ms_num == 0 {
+        return Just(Interaction::Empty).boxed();
+    }
+    let leaf = leaf_strategy(lf_num, ms_num, gt_num, config.action_density);
+    let operators = config.operator_weights.get_weighted_operators();
+    if operators.is_empty() || config.max_depth == 0 {
+        return leaf;
+    }
+    leaf.prop_recursive(
+        config.max_depth,
+        256,
+        2,
+        move |inner| {
+            let pair = || (inner.clone(),inner.clone());
+            let coregion = || btree_set(0..lf_num, 1..=lf_num).prop_map(|cr| cr.into_iter().collect::<Vec<usize>>());
+            let weighted : Vec<(u32,BoxedStrategy<Interaction>)> = operators.iter().map(|(operator,weight)| {
+                let strategy = match operator {
+                    GeneratedOperator::Strict => {
+                        pair().prop_map(|(i1,i2)| Interaction::Strict(Box::new(i1),Box::new(i2))).boxed()
+                    },
+                    GeneratedOperator::Seq => {
+                        pair().prop_map(|(i1,i2)| Interaction::CoReg(vec![],Box::new(i1),Box::new(i2))).boxed()
+                    },
+                    GeneratedOperator::CoReg => {
+                        (coregion(),inner.clone(),inner.clone())
+                            .prop_map(|(cr,i1,i2)| Interaction::CoReg(cr,Box::new(i1),Box::new(i2))).boxed()
+                    },
+                    GeneratedOperator::Alt => {
+                        pair().prop_map(|(i1,i2)| Interaction::Alt(Box::new(i1),Box::new(i2))).boxed()
+                    },
+                    GeneratedOperator::LoopStrict => {
+                        inner.clone().prop_map(|i1| Interaction::Loop(LoopKind::SStrictSeq,Box::new(i1))).boxed()
+                    },
+                    GeneratedOperator::LoopHeadFirst => {
+                        inner.clone().prop_map(|i1| Interaction::Loop(LoopKind::HHeadFirstWS,Box::new(i1))).boxed()
+                    },
+                    GeneratedOperator::LoopWeak => {
+                        inner.clone().prop_map(|i1| Interaction::Loop(LoopKind::Coreg(vec![]),Box::new(i1))).boxed()
+                    },
+                    GeneratedOperator::LoopCoReg => {
+                        (coregion(),inner.clone())
+                            .prop_map(|(cr,i1)| Interaction::Loop(LoopKind::Coreg(cr),Box::new(i1))).boxed()
+                    },
+                    GeneratedOperator::And => {
+                        pair().prop_map(|(i1,i2)| Interaction::And(Box::new(i1),Box::new(i2))).boxed()
+                    },
+                    GeneratedOperator::Sync => {
+                        let sync_acts = btree_set(trace_action_strategy(lf_num,ms_num), 1..=2)
+                            .prop_map(|acts| acts.into_iter().collect::<Vec<TraceAction>>());
+                        (sync_acts,inner.clone(),inner.clone())
+                            .prop_map(|(acts,i1,i2)| Interaction::Sync(acts,Box::new(i1),Box::new(i2))).boxed()
+                    }
+                };
+                (*weight,strategy)
+            }).collect();
+            Union::new_weighted(weighted)
+        }
+    ).boxed()
+}
+
+fn trace_action_strategy(lf_num : usize, ms_num : usize) -> impl Strategy<Value = TraceAction> {
+    (0..lf_num, any::<bool>(), 0..ms_num).prop_map(|(lf_id,is_emission,ms_id)| {
+        let act_kind = if is_emission {TraceActionKind::Emission} else {TraceActionKind::Reception};
+        TraceAction::new(lf_id,act_kind,ms_id)
+    })
+}
+
+fn leaf_strategy(
+    lf_num : usize,
+    ms_num : usize,
+    gt_num : usize,
+    action_density : f64
+) -> BoxedStrategy<Interaction> {
+    let gate = if gt_num > 0 {
+        proptest::option::weighted(0.25, 0..gt_num).boxed()
+    } else {
+        Just(None).boxed()
+    };
+    let emission = (0..lf_num, 0..ms_num, gate.clone()).prop_map(
+        |(lf_id,ms_id,gate)| Interaction::Emission(EmissionAction::new(lf_id, ms_id, gate.into_iter().collect()))
+    );
+    let reception = (gate, 0..ms_num, 0..lf_num).prop_map(
+        |(gate,ms_id,lf_id)| Interaction::Reception(ReceptionAction::new(gate, ms_id, lf_id))
+    );
+    let message_passing = (0..lf_num, 0..ms_num, 0..lf_num).prop_map(
+        |(lf_id,ms_id,targ_lf_id)| Interaction::Strict(
+            Box::new(Interaction::Emission(EmissionAction::new(lf_id, ms_id, vec![]))),
+            Box::new(Interaction::Reception(ReceptionAction::new(None, ms_id, targ_lf_id)))
+        )
+    );
+    let action_weight = (action_density * DENSITY_PRECISION).round() as u32;
+    let empty_weight = DENSITY_PRECISION as u32 - action_weight.min(DENSITY_PRECISION as u32);
+    let mut weighted = vec![];
+    if empty_weight > 0 {
+        weighted.push((empty_weight,Just(Interaction::Empty).boxed()));
+    }
+    if action_weight > 0 {
+        weighted.push((action_weight,Union::new_weighted(vec![
+            (1,emission.boxed()),
+            (1,reception.boxed()),
+            (2,message_passing.boxed())
+        ]).boxed()));
+    }
+    Union::new_weighted(weighted).boxed()
+}
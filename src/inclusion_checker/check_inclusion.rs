@@ -14,24 +14,27 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-use graph_process_manager_core::{process::{filter::GenericFiltersManager, logger::AbstractProcessLogger, manager::GenericProcessManager}, queue::{priorities::GenericProcessPriorities, strategy::QueueSearchStrategy}};
+use graph_process_manager_core::{process::{filter::{AbstractStepFilter, GenericFiltersManager}, logger::AbstractProcessLogger, manager::GenericProcessManager}, queue::priorities::GenericProcessPriorities};
 use graph_process_manager_loggers::graphviz::{drawers::node_drawer::CustomNodeDrawerForGraphvizLogger, format::GraphVizProcessLoggerLayout, logger::{GenericGraphVizLogger, GenericGraphVizLoggerConfiguration}};
 use graphviz_dot_builder::traits::GraphVizOutputFormat;
 use maplit::btreeset;
 use simple_term_rewriter::{core::terms::conversion::to_rewritable_term::FromDomainSpecificTermToRewritableTerm, metrics::TermMetrics};
 
-use crate::{core::{general_context::GeneralContext, syntax::interaction::Interaction}, inclusion_checker::loggers::glog::{all_the_rest_drawer::HibouInclusionCheckingAllTheRestDrawer, legend_writer::HibouInclusionCheckingLegendWriter, node_drawer::HibouInclusionCheckingNodeDrawer}, interfaces::HibouGraphvizLoggerParam, rewriting::{lang::HibouRewritableLangOperator, metrics::InteractionTermSymbolMetrics}, seqdiag_lib_interface::io::InteractionDrawingKind};
+use crate::{core::{general_context::GeneralContext, semantics::trace_action::TraceAction, syntax::interaction::Interaction}, inclusion_checker::loggers::glog::{all_the_rest_drawer::HibouInclusionCheckingAllTheRestDrawer, legend_writer::HibouInclusionCheckingLegendWriter, node_drawer::HibouInclusionCheckingNodeDrawer}, interfaces::HibouGraphvizLoggerParam, rewriting::{lang::HibouRewritableLangOperator, metrics::InteractionTermSymbolMetrics}, seqdiag_lib_interface::io::InteractionDrawingKind};
 
-use super::process::{conf::InteractionInclusionCheckingConfig, context::InteractionInclusionCheckingContextAndParameterization, filter::{InteractionInclusionCheckingNodePreFilter, InteractionInclusionCheckingStepFilter}, node::InteractionInclusionCheckingNode, priorities::InteractionInclusionCheckingPriorities};
+use super::options::InteractionInclusionCheckingOptions;
+use super::process::{conf::InteractionInclusionCheckingConfig, context::InteractionInclusionCheckingContextAndParameterization, filter::{InteractionInclusionCheckingNodePreFilter, InteractionInclusionCheckingStepFilter}, node::InteractionInclusionCheckingNode, priorities::InteractionInclusionCheckingPriorities, state::InteractionInclusionCheckingGlobalState};
 
 
 
 
 
-#[derive(Clone, PartialEq, Eq, Hash)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum InteractionInclusionGlobalVerdict {
     IsIncluded,
-    IsNotIncluded,
+    // with a counterexample i.e. the actions executed from the initial node
+    // up to the node where non-inclusion was established
+    IsNotIncluded(Vec<TraceAction>),
     IsIncludedUpToExploredSemantics
 }
 
@@ -41,7 +44,7 @@ impl std::fmt::Display for InteractionInclusionGlobalVerdict {
             InteractionInclusionGlobalVerdict::IsIncluded => {
                 write!(f, "IsIncluded")
             },
-            InteractionInclusionGlobalVerdict::IsNotIncluded => {
+            InteractionInclusionGlobalVerdict::IsNotIncluded(_) => {
                 write!(f, "IsNotIncluded")
             },
             InteractionInclusionGlobalVerdict::IsIncludedUpToExploredSemantics => {
@@ -94,10 +97,36 @@ fn get_graphviz_logger_from_param(
 
 
 
+/**
+ * When non-inclusion is established, the verdict carries a counterexample:
+ * a behaviour of the included candidate (a prefix of one, if no including candidate remains)
+ * which is not allowed by the including candidate.
+ * Factoring out common contexts is only a heuristic and the actions executed after it
+ * do not constitute a behaviour of the original interactions.
+ * Hence, if non-inclusion is established after such a simplification,
+ * the check is performed again without simplifications, with the same options,
+ * so as to obtain an exact counterexample.
+ * If this second pass is cut short before establishing non-inclusion,
+ * the counterexample of the first pass is returned, in which case it may be inexact.
+ **/
 pub fn check_inclusion_of_interactions(
     included_candidate : &Interaction,
     including_candidate : &Interaction,
     graphviz_param : Option<(&GeneralContext,&str,&str,&HibouGraphvizLoggerParam)>
+) -> InteractionInclusionGlobalVerdict {
+    check_inclusion_of_interactions_with_options(
+        included_candidate,
+        including_candidate,
+        &InteractionInclusionCheckingOptions::default(),
+        graphviz_param
+    )
+}
+
+pub fn check_inclusion_of_interactions_with_options(
+    included_candidate : &Interaction,
+    including_candidate : &Interaction,
+    options : &InteractionInclusionCheckingOptions,
+    graphviz_param : Option<(&GeneralContext,&str,&str,&HibouGraphvizLoggerParam)>
 ) -> InteractionInclusionGlobalVerdict {
     let loggers : Vec<Box< dyn AbstractProcessLogger<InteractionInclusionCheckingConfig>>> = match graphviz_param {
         None => {
@@ -108,45 +137,106 @@ pub fn check_inclusion_of_interactions(
         }
     };
 
-    let context_and_param = InteractionInclusionCheckingContextAndParameterization{};
+    let final_state = run_inclusion_checking_process(
+        included_candidate,
+        including_candidate,
+        options,
+        true,
+        loggers
+    );
+
+    match final_state.inclusion_verdict {
+        InteractionInclusionGlobalVerdict::IsNotIncluded(inexact_counterexample) if final_state.has_simplified_contexts => {
+            let exact_verdict = run_inclusion_checking_process(
+                included_candidate,
+                including_candidate,
+                options,
+                false,
+                vec![]
+            ).inclusion_verdict;
+            match exact_verdict {
+                InteractionInclusionGlobalVerdict::IsIncludedUpToExploredSemantics => {
+                    // the second pass has been cut short, the verdict of the first one is kept
+                    InteractionInclusionGlobalVerdict::IsNotIncluded(inexact_counterexample)
+                },
+                _ => {
+                    exact_verdict
+                }
+            }
+        },
+        verdict => {
+            verdict
+        }
+    }
+}
+
+
+
+fn run_inclusion_checking_process(
+    included_candidate : &Interaction,
+    including_candidate : &Interaction,
+    options : &InteractionInclusionCheckingOptions,
+    simplify_contexts : bool,
+    loggers : Vec<Box< dyn AbstractProcessLogger<InteractionInclusionCheckingConfig>>>
+) -> InteractionInclusionCheckingGlobalState {
+    let context_and_param = InteractionInclusionCheckingContextAndParameterization{
+        simplify_contexts,
+        delayed_alt : options.delayed_alt,
+        canonize_at_each_step : options.canonize_at_each_step
+    };
 
     // ***
 
-    let int_metrics = TermMetrics::<HibouRewritableLangOperator,InteractionTermSymbolMetrics>::extract_from_term(
-        &included_candidate.to_rewritable_term()
-    );
-    let max_loop_instanciations = match int_metrics.metrics_count.get(&InteractionTermSymbolMetrics::AnyLoop) {
-        None => {
-            1
+    let max_loop_instanciations = match options.max_loop_instanciations {
+        Some(max_loop_instanciations) => {
+            max_loop_instanciations
         },
-        Some(loop_number) => {
-            (2*loop_number) + 1
+        None => {
+            let int_metrics = TermMetrics::<HibouRewritableLangOperator,InteractionTermSymbolMetrics>::extract_from_term(
+                &included_candidate.to_rewritable_term()
+            );
+            match int_metrics.metrics_count.get(&InteractionTermSymbolMetrics::AnyLoop) {
+                None => {
+                    1
+                },
+                Some(loop_number) => {
+                    (2*loop_number) + 1
+                }
+            }
         }
     };
 
+    let mut step_filters : Vec<Box<dyn AbstractStepFilter<InteractionInclusionCheckingConfig>>> = vec![
+        Box::new(InteractionInclusionCheckingStepFilter::MaxLoopInstanciation(max_loop_instanciations))
+    ];
+    if let Some(max_node_number) = options.max_node_number {
+        step_filters.push(Box::new(InteractionInclusionCheckingStepFilter::MaxNodeNumber(max_node_number)));
+    }
+
     let initial_node = InteractionInclusionCheckingNode::new(
         included_candidate.clone(),
         0,
-        btreeset! {including_candidate.clone()}
+        btreeset! {including_candidate.clone()},
+        vec![]
     );
 
     let mut manager : GenericProcessManager<InteractionInclusionCheckingConfig> = GenericProcessManager::new(
         context_and_param,
-        QueueSearchStrategy::BFS,
+        options.get_strategy(),
         GenericProcessPriorities::new(InteractionInclusionCheckingPriorities{},false),
         GenericFiltersManager::new(
             vec![Box::new(InteractionInclusionCheckingNodePreFilter{})], 
             vec![], 
-            vec![Box::new(InteractionInclusionCheckingStepFilter::MaxLoopInstanciation(max_loop_instanciations))]
+            step_filters
         ),
         loggers,
-        true,
+        options.memoize,
         initial_node
     );
 
     let _ = manager.start_process();
 
-    manager.global_state.inclusion_verdict
+    manager.global_state
 }
 
 
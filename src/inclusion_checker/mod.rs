@@ -16,5 +16,6 @@ limitations under the License.
 
 pub(in crate::inclusion_checker) mod process;
 pub(crate) mod loggers;
+pub mod options;
 pub mod check_inclusion;
 
@@ -0,0 +1,71 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+
+
+/**
+ * Options of the inclusion checking process.
+ * The defaults correspond to a complete exploration up to the loop instantiation bound.
+ * Setting a node budget or a lower loop bound trades completeness for time,
+ * in which case the verdict may be *IsIncludedUpToExploredSemantics*.
+ **/
+pub struct InteractionInclusionCheckingOptions {
+    // the order in which the search space is explored
+    pub strategy : QueueSearchStrategy,
+    // if set, no more steps are fired once that many nodes have been reached
+    pub max_node_number : Option<u32>,
+    // if not set, the bound is derived from the number of loops in the included candidate
+    pub max_loop_instanciations : Option<u32>,
+    // whether or not actions that are common to both branches of an alternative are executed on both at once
+    pub delayed_alt : bool,
+    // whether or not candidates are put in canonical form before the execution of each action
+    pub canonize_at_each_step : bool,
+    // whether or not already reached nodes are recognized
+    pub memoize : bool
+}
+
+impl InteractionInclusionCheckingOptions {
+
+    pub fn new(
+        strategy : QueueSearchStrategy,
+        max_node_number : Option<u32>,
+        max_loop_instanciations : Option<u32>,
+        delayed_alt : bool,
+        canonize_at_each_step : bool,
+        memoize : bool
+    ) -> Self {
+        Self { strategy, max_node_number, max_loop_instanciations, delayed_alt, canonize_at_each_step, memoize }
+    }
+
+    // the strategy is not cloneable and the process may need to be run twice
+    pub(in crate::inclusion_checker) fn get_strategy(&self) -> QueueSearchStrategy {
+        match self.strategy {
+            QueueSearchStrategy::BFS => QueueSearchStrategy::BFS,
+            QueueSearchStrategy::DFS => QueueSearchStrategy::DFS,
+            QueueSearchStrategy::HCS => QueueSearchStrategy::HCS
+        }
+    }
+
+}
+
+impl Default for InteractionInclusionCheckingOptions {
+    fn default() -> Self {
+        Self::new(QueueSearchStrategy::BFS, None, None, true, true, true)
+    }
+}
@@ -17,5 +17,10 @@ limitations under the License.
 
 
 
-pub struct InteractionInclusionCheckingContextAndParameterization {}
+pub struct InteractionInclusionCheckingContextAndParameterization {
+    // whether or not common contexts are factored out of the included and including candidates
+    pub simplify_contexts : bool,
+    pub delayed_alt : bool,
+    pub canonize_at_each_step : bool
+}
 
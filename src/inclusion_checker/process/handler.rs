@@ -34,12 +34,14 @@ impl AbstractAlgorithmOperationHandler<InteractionInclusionCheckingConfig> for I
 
     fn process_new_step(
         _context_and_param : &InteractionInclusionCheckingContextAndParameterization,
-        _global_state : &mut InteractionInclusionCheckingGlobalState,
+        global_state : &mut InteractionInclusionCheckingGlobalState,
         parent_node : &InteractionInclusionCheckingNode,
         step_to_process : &mut InteractionInclusionCheckingStepKind
     ) -> InteractionInclusionCheckingNode {
         match step_to_process {
             InteractionInclusionCheckingStepKind::ExecuteAction(frt_elt,next_including_candidates) => {
+                let mut executed_actions = parent_node.executed_actions.clone();
+                executed_actions.extend(frt_elt.target_actions.iter().cloned());
                 InteractionInclusionCheckingNode::new(
                     execute_interaction(
                         &parent_node.included_candidate, 
@@ -48,79 +50,87 @@ impl AbstractAlgorithmOperationHandler<InteractionInclusionCheckingConfig> for I
                         false
                     ).interaction,
                     parent_node.loop_depth + frt_elt.max_loop_depth,
-                    next_including_candidates.clone()
+                    next_including_candidates.clone(),
+                    executed_actions
                 )
             },
             InteractionInclusionCheckingStepKind::Normalize(included_candidate,including_candidates) => {
                 InteractionInclusionCheckingNode::new(
                     included_candidate.clone(), 
                     parent_node.loop_depth,
-                    including_candidates.clone()
+                    including_candidates.clone(),
+                    parent_node.executed_actions.clone()
                 )
             },
             InteractionInclusionCheckingStepKind::ContextSimplification(included_candidate,including_candidates) => {
+                global_state.has_simplified_contexts = true;
                 InteractionInclusionCheckingNode::new(
                     included_candidate.clone(), 
                     parent_node.loop_depth,
-                    including_candidates.clone()
+                    including_candidates.clone(),
+                    parent_node.executed_actions.clone()
                 )
             }
         }
     }
 
     fn collect_next_steps(
-        _context_and_param : &InteractionInclusionCheckingContextAndParameterization,
+        context_and_param : &InteractionInclusionCheckingContextAndParameterization,
         _global_state : &mut InteractionInclusionCheckingGlobalState,
         parent_node : &InteractionInclusionCheckingNode
     ) -> Vec<InteractionInclusionCheckingStepKind> {
         // ***
-        if let Some((smpl_included,smpl_including)) = simplify_context(
-            &parent_node.included_candidate,
-            &parent_node.including_candidates
-        ) {
-            return vec![
-                InteractionInclusionCheckingStepKind::ContextSimplification(
-                    smpl_included, 
-                    smpl_including
-                )
-                ];
+        if context_and_param.simplify_contexts {
+            if let Some((smpl_included,smpl_including)) = simplify_context(
+                &parent_node.included_candidate,
+                &parent_node.including_candidates
+            ) {
+                return vec![
+                    InteractionInclusionCheckingStepKind::ContextSimplification(
+                        smpl_included, 
+                        smpl_including
+                    )
+                    ];
+            }
         }
         // ***
         // *** ***
         // ***
-        let normalized_included_candidate = canonize_interaction(
-            &parent_node.included_candidate, 
-            None, 
-            true,
-            true
-        );
-        let mut normalized_including_candidates = btreeset![];
-        for cand in &parent_node.including_candidates {
-            normalized_including_candidates.insert(
-                canonize_interaction(
-                        cand, 
-                    None, 
-                    true,
-                    true
-                )
+        if context_and_param.canonize_at_each_step {
+            let normalized_included_candidate = canonize_interaction(
+                &parent_node.included_candidate, 
+                None, 
+                true,
+                true
             );
-        }
-        if normalized_included_candidate != parent_node.included_candidate || normalized_including_candidates != parent_node.including_candidates {
-            return vec![
-                InteractionInclusionCheckingStepKind::Normalize(
-                    normalized_included_candidate, 
-                    normalized_including_candidates
-                )
-                ];
+            let mut normalized_including_candidates = btreeset![];
+            for cand in &parent_node.including_candidates {
+                normalized_including_candidates.insert(
+                    canonize_interaction(
+                            cand, 
+                        None, 
+                        true,
+                        true
+                    )
+                );
+            }
+            if normalized_included_candidate != parent_node.included_candidate || normalized_including_candidates != parent_node.including_candidates {
+                return vec![
+                    InteractionInclusionCheckingStepKind::Normalize(
+                        normalized_included_candidate, 
+                        normalized_including_candidates
+                    )
+                    ];
+            }
         }
         // ***
         // *** ***
         // ***
         let mut executions = vec![];
-        for frt_elt in global_frontier(&parent_node.included_candidate,  true) {
+        for frt_elt in global_frontier(&parent_node.included_candidate,  context_and_param.delayed_alt) {
             let mut next_including = btreeset! {};
             for inc_cand in &parent_node.including_candidates {
-                for cand_frt_elt in global_frontier(inc_cand, true) {
+                for cand_frt_elt in global_frontier(inc_cand, context_and_param.delayed_alt) {
                     if cand_frt_elt.target_actions == frt_elt.target_actions {
                         let follow_up = execute_interaction(
                             inc_cand, 
@@ -20,6 +20,7 @@ limitations under the License.
 use std::collections::BTreeSet;
 
 use graph_process_manager_core::process::config::AbstractNodeKind;
+use crate::core::semantics::trace_action::TraceAction;
 use crate::core::syntax::interaction::Interaction;
 
 
@@ -29,16 +30,19 @@ use crate::core::syntax::interaction::Interaction;
 pub struct InteractionInclusionCheckingNode {
     pub included_candidate : Interaction,
     pub loop_depth : u32,
-    pub including_candidates : BTreeSet<Interaction>
+    pub including_candidates : BTreeSet<Interaction>,
+    // the actions executed from the initial node, which are not relevant for memoization
+    pub executed_actions : Vec<TraceAction>
 }
 
 impl InteractionInclusionCheckingNode {
     pub fn new(
         included_candidate : Interaction,
         loop_depth : u32,
-        including_candidates : BTreeSet<Interaction>
+        including_candidates : BTreeSet<Interaction>,
+        executed_actions : Vec<TraceAction>
     ) -> Self {
-        Self { included_candidate, loop_depth, including_candidates }
+        Self { included_candidate, loop_depth, including_candidates, executed_actions }
     }
 }
 
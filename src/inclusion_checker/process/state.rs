@@ -30,7 +30,9 @@ use super::step::InteractionInclusionCheckingStepKind;
 
 pub struct InteractionInclusionCheckingGlobalState {
     pub inclusion_verdict : InteractionInclusionGlobalVerdict,
-    pub node_count : u32
+    pub node_count : u32,
+    // whether or not a common context has been factored out at some point
+    pub has_simplified_contexts : bool
 }
 
 
@@ -40,7 +42,7 @@ impl AbstractProcessMutablePersistentState<InteractionInclusionCheckingConfig> f
         _context_and_param: &InteractionInclusionCheckingContextAndParameterization,
         _initial_node : &InteractionInclusionCheckingNode
     ) -> Self {
-        Self{inclusion_verdict : InteractionInclusionGlobalVerdict::IsIncluded, node_count : 0}
+        Self{inclusion_verdict : InteractionInclusionGlobalVerdict::IsIncluded, node_count : 0, has_simplified_contexts : false}
     }
 
     fn update_on_node_reached(
@@ -63,7 +65,7 @@ impl AbstractProcessMutablePersistentState<InteractionInclusionCheckingConfig> f
     fn update_on_filtered(
         &mut self,
         _context_and_param: &InteractionInclusionCheckingContextAndParameterization,
-        _parent_node: &InteractionInclusionCheckingNode,
+        parent_node: &InteractionInclusionCheckingNode,
         filtration_result: &InteractionInclusionCheckingFiltrationResult
     ) {
         match filtration_result {
@@ -71,10 +73,10 @@ impl AbstractProcessMutablePersistentState<InteractionInclusionCheckingConfig> f
                 // nothing
             },
             InteractionInclusionCheckingFiltrationResult::NoMoreIncludingCandidates => {
-                self.inclusion_verdict = InteractionInclusionGlobalVerdict::IsNotIncluded;
+                self.inclusion_verdict = InteractionInclusionGlobalVerdict::IsNotIncluded(parent_node.executed_actions.clone());
             },
             InteractionInclusionCheckingFiltrationResult::NoCandidateAcceptsEmptyTrace => {
-                self.inclusion_verdict = InteractionInclusionGlobalVerdict::IsNotIncluded;
+                self.inclusion_verdict = InteractionInclusionGlobalVerdict::IsNotIncluded(parent_node.executed_actions.clone());
             },
             InteractionInclusionCheckingFiltrationResult::MaxNodeNumber => {
                 self.inclusion_verdict = InteractionInclusionGlobalVerdict::IsIncludedUpToExploredSemantics;
@@ -89,7 +91,7 @@ impl AbstractProcessMutablePersistentState<InteractionInclusionCheckingConfig> f
         &self,
         _context_and_param: &InteractionInclusionCheckingContextAndParameterization
     ) -> bool {
-        if self.inclusion_verdict == InteractionInclusionGlobalVerdict::IsNotIncluded {
+        if let InteractionInclusionGlobalVerdict::IsNotIncluded(_) = self.inclusion_verdict {
             return true;
         }
         false 
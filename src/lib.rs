@@ -20,8 +20,14 @@ pub mod commons;
 pub mod core;
 pub mod rewriting;
 pub mod inclusion_checker;
+pub mod equivalence_checker;
+pub mod analysis;
 pub mod seqdiag_lib_interface;
 pub mod interfaces;
+pub mod error;
+#[cfg(feature = "generation")]
+pub mod generation;
+pub mod automata;
 
 
 
@@ -14,26 +14,20 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-use graph_process_manager_core::{process::{filter::GenericFiltersManager, logger::AbstractProcessLogger, manager::GenericProcessManager}, queue::{priorities::GenericProcessPriorities, strategy::QueueSearchStrategy}};
+use graph_process_manager_core::process::logger::AbstractProcessLogger;
 use graph_process_manager_loggers::graphviz::{drawers::node_drawer::CustomNodeDrawerForGraphvizLogger, format::GraphVizProcessLoggerLayout, logger::{GenericGraphVizLogger, GenericGraphVizLoggerConfiguration}};
 use graphviz_dot_builder::traits::GraphVizOutputFormat;
-use simple_term_rewriter::{core::terms::conversion::from_rewritable_term::FromRewritableTermToDomainSpecificTerm, rewriting_process::context::AbstractRewritingPhase};
-use simple_term_rewriter::core::terms::conversion::to_rewritable_term::FromDomainSpecificTermToRewritableTerm;
-use simple_term_rewriter::core::rule::RewriteRule;
 use simple_term_rewriter::rewriting_process::conf::RewriteConfig;
-use simple_term_rewriter::rewriting_process::context::RewritingProcessContextAndParameterization;
-use simple_term_rewriter::rewriting_process::node::RewriteNodeKind;
-use simple_term_rewriter::rewriting_process::priorities::RewritePriorities;
 
 use crate::{core::{general_context::GeneralContext, syntax::interaction::Interaction}, interfaces::HibouGraphvizLoggerParam, rewriting::loggers::glog::{all_the_rest_drawer::HibouRewritingAllTheRestDrawer, legend_writer::HibouRewritingLegendWriter, node_drawer::HibouRewritingNodeDrawer}, seqdiag_lib_interface::io::InteractionDrawingKind};
 
-use super::{lang::HibouRewritableLangOperator, rules::high_level_hibou_rewrite_rules::HighLevelHibouRewriteRules};
+use super::{derivation::{IrreducibleForm, RewriteDerivation}, lang::HibouRewritableLangOperator, pipeline::RewritingPipeline};
 
 
 
 
 
-fn get_graphviz_logger_from_param(
+pub(in crate::rewriting) fn get_graphviz_logger_from_param(
     gen_ctx : &GeneralContext,
     gv_log_param : &HibouGraphvizLoggerParam,
     fname : &str
@@ -68,148 +62,94 @@ fn get_graphviz_logger_from_param(
 
 
 
-
-fn get_base_rules(coreg_simplifications : bool) -> Vec<Box<dyn RewriteRule<HibouRewritableLangOperator>>> {
-    let mut rules = vec![
-        HighLevelHibouRewriteRules::StrictFlushRight.get_low_level_rewrite_rule(),
-        HighLevelHibouRewriteRules::AltAndCoregFlushRight.get_low_level_rewrite_rule(),
-        HighLevelHibouRewriteRules::ReorderSubInteractionsUnderAlt.get_low_level_rewrite_rule(),
-        HighLevelHibouRewriteRules::ReorderSubInteractionsUnderCoreg.get_low_level_rewrite_rule(),
-        HighLevelHibouRewriteRules::EpsilonFixpoint.get_low_level_rewrite_rule(),
-        HighLevelHibouRewriteRules::EpsilonNeutral.get_low_level_rewrite_rule(),
-        HighLevelHibouRewriteRules::KleeneNesting.get_low_level_rewrite_rule(),
-        HighLevelHibouRewriteRules::KleeneTighteningModuloAC.get_low_level_rewrite_rule(),
-        HighLevelHibouRewriteRules::KleeneRolling.get_low_level_rewrite_rule(),
-        HighLevelHibouRewriteRules::StrictnessRelaxationBinary.get_low_level_rewrite_rule(),
-        HighLevelHibouRewriteRules::StrictnessRelaxationUnary.get_low_level_rewrite_rule(),
-        HighLevelHibouRewriteRules::BasicAltDeduplication.get_low_level_rewrite_rule(),
-        HighLevelHibouRewriteRules::SequencingCompatibilityLeft.get_low_level_rewrite_rule(),
-        HighLevelHibouRewriteRules::SequencingCompatibilityRight.get_low_level_rewrite_rule(),
-        HighLevelHibouRewriteRules::KleeneDesequencing.get_low_level_rewrite_rule(),
-    ];
-    if coreg_simplifications {
-        rules.extend(
-            vec![
-                HighLevelHibouRewriteRules::CoregionMinimizationBasic.get_low_level_rewrite_rule(),
-                HighLevelHibouRewriteRules::CoregionMinimizationKleene.get_low_level_rewrite_rule(),
-            ]
-        );
-    }
-    rules
-}
-
-fn get_phase_1(coreg_simplifications : bool) -> Vec<Box<dyn RewriteRule<HibouRewritableLangOperator>>> {
-    let mut rules = get_base_rules(coreg_simplifications);
-    rules.extend(
-        vec![
-            HighLevelHibouRewriteRules::DeFactorizeLeft.get_low_level_rewrite_rule(),
-            HighLevelHibouRewriteRules::DeFactorizeRight.get_low_level_rewrite_rule()
-        ]
-    );
-    rules 
-}
-
-fn get_phase_2(coreg_simplifications : bool) -> Vec<Box<dyn RewriteRule<HibouRewritableLangOperator>>> {
-    let mut rules = get_base_rules(coreg_simplifications);
-    rules.extend(
-        vec![
-            HighLevelHibouRewriteRules::FactorizeRight.get_low_level_rewrite_rule()
-        ]
-    );
-    rules 
-}
-
-fn get_phase_3(coreg_simplifications : bool) -> Vec<Box<dyn RewriteRule<HibouRewritableLangOperator>>> {
-    let mut rules = get_base_rules(coreg_simplifications);
-    rules.extend(
-        vec![
-            HighLevelHibouRewriteRules::FactorizeLeft.get_low_level_rewrite_rule()
-        ]
-    );
-    rules 
-}
-
-
-
+/**
+ * Canonizes the interaction with the three phases canonization.
+ * Coregions are not reordered modulo AC, hence interactions which only differ in the order
+ * of independent sub-interactions (e.g. exchanges between distinct lifelines under a *seq*)
+ * may have distinct canonical forms.
+ * Regression checks which compare canonical forms for equality must therefore
+ * use "canonize_interaction_with_coregion_reordering" instead.
+ * The phases of the canonization are not bounded, hence the rewriting cannot fail.
+ **/
 pub fn canonize_interaction(
     int : &Interaction,
     graphviz_param : Option<(&GeneralContext,&str,&HibouGraphvizLoggerParam)>,
     keep_only_one : bool,
     coreg_simplifications : bool
 ) -> Interaction {
-    let loggers = match graphviz_param {
-        None => {
-            vec![]
-        },
-        Some((gen_ctx,fname,gv_log_param)) => {
-            get_graphviz_logger_from_param(gen_ctx, gv_log_param, fname)
-        }
-    };
-
-    let int_as_term = int.to_rewritable_term();
-
-    let phase1 = AbstractRewritingPhase::<HibouRewritableLangOperator>::new(
-        get_phase_1(coreg_simplifications),
-        Some(1),
-        Some(1)
-    );
-
-    let phase2 = AbstractRewritingPhase::new(
-        get_phase_2(coreg_simplifications),
-        Some(2),
-        Some(2)
-    );
-
-    let phase3 = AbstractRewritingPhase::new(
-        get_phase_3(coreg_simplifications),
-        Some(1),
-        None
-    );
-
-    let context_and_param = RewritingProcessContextAndParameterization::new(
-        vec![phase1,phase2,phase3],
-        keep_only_one
-    );
-
-    // ***
-
-    let mut manager : GenericProcessManager<RewriteConfig<HibouRewritableLangOperator>> = GenericProcessManager::new(
-        context_and_param,
-        QueueSearchStrategy::DFS,
-        GenericProcessPriorities::new(RewritePriorities{},false),
-        GenericFiltersManager::new(
-            vec![], 
-            vec![], 
-            vec![]
-        ),
-        loggers,
-        true,
-        RewriteNodeKind::new(
-            int_as_term,
-            0
-        )
-    );
-
-    let _ = manager.start_process();
-
-    let last_phase = manager.global_state.concrete_phases.last().unwrap();
-    let irreducible = &last_phase.final_irreducible_terms;
-    if irreducible.len() > 1 {
-        println!("WARNING : more than 1 irreducible term found");
-    }
-    let result = irreducible.into_iter().next().unwrap();
-
-    Interaction::from_rewritable_term(result)
+    RewritingPipeline::full_canonical(coreg_simplifications)
+        .keep_only_one(keep_only_one)
+        .rewrite(int, graphviz_param)
+        .unwrap()
 }
 
 
 
+/**
+ * Canonizes the interaction as "canonize_interaction" does
+ * and then removes the summands of alternatives that are included in other summands.
+ * This optional phase relies on inclusion checking and is hence costly.
+ **/
+pub fn canonize_interaction_with_summand_inclusion(
+    int : &Interaction,
+    graphviz_param : Option<(&GeneralContext,&str,&HibouGraphvizLoggerParam)>,
+    keep_only_one : bool,
+    coreg_simplifications : bool
+) -> Interaction {
+    RewritingPipeline::full_canonical_with_summand_inclusion(coreg_simplifications)
+        .keep_only_one(keep_only_one)
+        .rewrite(int, graphviz_param)
+        .unwrap()
+}
 
 
 
+/**
+ * Canonizes the interaction as "canonize_interaction" does with coregion simplifications,
+ * except that the coregions are reordered and minimized modulo AC.
+ * Interactions which only differ in the order of independent sub-interactions then canonize identically.
+ **/
+pub fn canonize_interaction_with_coregion_reordering(
+    int : &Interaction,
+    graphviz_param : Option<(&GeneralContext,&str,&HibouGraphvizLoggerParam)>,
+    keep_only_one : bool
+) -> Interaction {
+    RewritingPipeline::full_canonical_with_coregion_reordering()
+        .keep_only_one(keep_only_one)
+        .rewrite(int, graphviz_param)
+        .unwrap()
+}
 
 
 
+/**
+ * Canonizes the interaction as "canonize_interaction" does
+ * and returns the derivation which explains the canonical form that is obtained.
+ **/
+pub fn canonize_interaction_with_derivation(
+    int : &Interaction,
+    graphviz_param : Option<(&GeneralContext,&str,&HibouGraphvizLoggerParam)>,
+    keep_only_one : bool,
+    coreg_simplifications : bool
+) -> RewriteDerivation {
+    RewritingPipeline::full_canonical(coreg_simplifications)
+        .keep_only_one(keep_only_one)
+        .rewrite_with_derivation(int, graphviz_param)
+        .unwrap()
+}
 
 
 
+/**
+ * Returns all the canonical forms that the canonization may reach,
+ * each with the rule applications that lead to it.
+ **/
+pub fn canonize_interaction_to_all_irreducible_forms(
+    int : &Interaction,
+    graphviz_param : Option<(&GeneralContext,&str,&HibouGraphvizLoggerParam)>,
+    coreg_simplifications : bool
+) -> Vec<IrreducibleForm> {
+    RewritingPipeline::full_canonical(coreg_simplifications)
+        .rewrite_to_all_irreducible_forms(int, graphviz_param)
+        .unwrap()
+}
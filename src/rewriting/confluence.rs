@@ -0,0 +1,98 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashSet;
+
+use crate::core::syntax::interaction::Interaction;
+
+use super::derivation::RewriteRuleApplication;
+use super::pipeline::{RewritingPipeline, RewritingPipelinePhase};
+use super::rules::high_level_hibou_rewrite_rules::HighLevelHibouRewriteRules;
+
+
+
+/**
+ * A critical pair witnessing the non-confluence of a set of rewrite rules:
+ * from the source interaction, two different rule applications lead to terms
+ * which each have a single irreducible form, these two irreducible forms being different.
+ **/
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NonConfluenceDiagnostic {
+    pub source : Interaction,
+    pub left_application : RewriteRuleApplication,
+    pub left_irreducible_form : Interaction,
+    pub right_application : RewriteRuleApplication,
+    pub right_irreducible_form : Interaction
+}
+
+
+
+/**
+ * Explores all the ways of rewriting the given interaction with the given rules
+ * and reports the critical pairs from which the rewriting commits to distinct irreducible forms.
+ * Critical pairs which only lead to already diverging terms are not reported,
+ * so that each diagnostic points to where the divergence originates.
+ * An empty result means that the rules are confluent on this interaction
+ * (up to the explored derivations if the number of rewrite steps is bounded).
+ **/
+pub fn find_critical_pairs(
+    rules : &[HighLevelHibouRewriteRules],
+    int : &Interaction,
+    max_rewrite_steps : Option<u32>
+) -> Vec<NonConfluenceDiagnostic> {
+    let pipeline = RewritingPipeline::new()
+        .with_phase(RewritingPipelinePhase::new(rules.to_vec(), None, None))
+        .keep_only_one(false)
+        .max_rewrite_steps(max_rewrite_steps);
+    let graph = pipeline.explore_derivations(int, false, None);
+    let reachable = graph.get_reachable_final_nodes();
+    // ***
+    let mut diagnostics = vec![];
+    let mut reported_targets : HashSet<(u32,u32)> = HashSet::new();
+    let mut origins : Vec<&u32> = graph.steps.keys().collect();
+    origins.sort();
+    for origin in origins {
+        let steps = graph.steps.get(origin).unwrap();
+        for (left_id,(left_application,left_target)) in steps.iter().enumerate() {
+            for (right_application,right_target) in steps.iter().skip(left_id + 1) {
+                let (Some(left_application),Some(right_application)) = (left_application,right_application) else {
+                    continue;
+                };
+                let left_finals = reachable.get(left_target).unwrap();
+                let right_finals = reachable.get(right_target).unwrap();
+                if left_finals.len() != 1 || right_finals.len() != 1 || left_finals == right_finals {
+                    continue;
+                }
+                let left_irreducible_form = graph.interactions.get(left_finals.first().unwrap()).unwrap();
+                let right_irreducible_form = graph.interactions.get(right_finals.first().unwrap()).unwrap();
+                if left_irreducible_form == right_irreducible_form || !reported_targets.insert((*left_target,*right_target)) {
+                    continue;
+                }
+                diagnostics.push(
+                    NonConfluenceDiagnostic {
+                        source : graph.interactions.get(origin).unwrap().clone(),
+                        left_application : left_application.clone(),
+                        left_irreducible_form : left_irreducible_form.clone(),
+                        right_application : right_application.clone(),
+                        right_irreducible_form : right_irreducible_form.clone()
+                    }
+                );
+            }
+        }
+    }
+    diagnostics
+}
@@ -62,6 +62,11 @@ impl FromRewritableTermToDomainSpecificTerm<HibouRewritableLangOperator> for Int
                 let i1 = sub_terms.pop().unwrap();
                 Interaction::And(Box::new(i1),Box::new(i2))
             },
+            HibouRewritableLangOperator::Sync(sync_acts) => {
+                let i2 = sub_terms.pop().unwrap();
+                let i1 = sub_terms.pop().unwrap();
+                Interaction::Sync(sync_acts.clone(),Box::new(i1),Box::new(i2))
+            },
         }
     }
 
@@ -41,6 +41,9 @@ impl FromDomainSpecificTermToRewritableTerm<HibouRewritableLangOperator> for Int
             Interaction::And(_,_) => {
                 HibouRewritableLangOperator::And
             }
+            Interaction::Sync(sync_acts,_,_) => {
+                HibouRewritableLangOperator::Sync(sync_acts.clone())
+            }
             Interaction::Empty => {
                 HibouRewritableLangOperator::Empty
             },
@@ -70,6 +73,9 @@ impl FromDomainSpecificTermToRewritableTerm<HibouRewritableLangOperator> for Int
             Interaction::And(i1, i2) => {
                 vec![&*i1,&*i2]
             }
+            Interaction::Sync(_,i1, i2) => {
+                vec![&*i1,&*i2]
+            }
             Interaction::Empty => {
                 vec![]
             },
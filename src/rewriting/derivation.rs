@@ -0,0 +1,445 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+use simple_term_rewriter::core::terms::conversion::from_rewritable_term::FromRewritableTermToDomainSpecificTerm;
+use simple_term_rewriter::rewriting_process::state::RewritingProcessState;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::syntax::interaction::Interaction;
+use crate::seqdiag_lib_interface::io::{draw_interaction_on_file, interaction_as_text, InteractionDrawingKind};
+
+use super::lang::HibouRewritableLangOperator;
+use super::loggers::derivation_recorder::HibouRewritingDerivationRecorder;
+use super::pipeline::RewritingPipelinePhase;
+use super::rules::high_level_hibou_rewrite_rules::HighLevelHibouRewriteRules;
+
+
+
+/**
+ * The application of a rewrite rule at a given position,
+ * the position being given as the coordinates of the sub-term from the root of the term.
+ **/
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct RewriteRuleApplication {
+    pub rule : HighLevelHibouRewriteRules,
+    pub position : Vec<usize>
+}
+
+impl RewriteRuleApplication {
+    pub fn new(rule : HighLevelHibouRewriteRules, position : Vec<usize>) -> Self {
+        Self { rule, position }
+    }
+}
+
+
+
+/**
+ * A final irreducible term of a rewriting process
+ * together with the rule applications that lead to it from the initial interaction.
+ **/
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct IrreducibleForm {
+    pub interaction : Interaction,
+    pub rule_path : Vec<RewriteRuleApplication>
+}
+
+impl IrreducibleForm {
+    pub fn new(interaction : Interaction, rule_path : Vec<RewriteRuleApplication>) -> Self {
+        Self { interaction, rule_path }
+    }
+}
+
+
+
+/**
+ * The application of a rewrite rule, described as in the rewriting process,
+ * at the position of the redex, with the terms before and after its application.
+ **/
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct RewriteDerivationStep {
+    pub rule_desc : String,
+    pub position : Vec<usize>,
+    pub before : Interaction,
+    pub after : Interaction
+}
+
+impl RewriteDerivationStep {
+    pub fn new(rule_desc : String, position : Vec<usize>, before : Interaction, after : Interaction) -> Self {
+        Self { rule_desc, position, before, after }
+    }
+}
+
+
+
+/**
+ * The ordered rewrite steps which lead from an initial interaction to the result of its rewriting.
+ **/
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct RewriteDerivation {
+    pub initial : Interaction,
+    pub steps : Vec<RewriteDerivationStep>
+}
+
+impl RewriteDerivation {
+
+    pub fn new(initial : Interaction, steps : Vec<RewriteDerivationStep>) -> Self {
+        Self { initial, steps }
+    }
+
+    pub fn get_result(&self) -> &Interaction {
+        match self.steps.last() {
+            Some(step) => {
+                &step.after
+            },
+            None => {
+                &self.initial
+            }
+        }
+    }
+
+    pub fn as_text(&self, ctx : &GeneralContext) -> String {
+        let mut text = format!("initial:\n{}\n", interaction_as_text(ctx, &self.initial, true));
+        for (step_id,step) in self.steps.iter().enumerate() {
+            text.push_str(
+                &format!(
+                    "step {} : {} at position {:?}\nbefore:\n{}\nafter:\n{}\n",
+                    step_id + 1,
+                    step.rule_desc,
+                    step.position,
+                    interaction_as_text(ctx, &step.before, true),
+                    interaction_as_text(ctx, &step.after, true)
+                )
+            );
+        }
+        text
+    }
+
+    pub fn as_json(&self, ctx : &GeneralContext) -> String {
+        let steps : Vec<String> = self.steps.iter().map(
+            |step| format!(
+                "{{\"rule\":{},\"position\":{:?},\"before\":{},\"after\":{}}}",
+                as_json_string(&step.rule_desc),
+                step.position,
+                as_json_string(&interaction_as_text(ctx, &step.before, true)),
+                as_json_string(&interaction_as_text(ctx, &step.after, true))
+            )
+        ).collect();
+        format!(
+            "{{\"initial\":{},\"steps\":[{}],\"result\":{}}}",
+            as_json_string(&interaction_as_text(ctx, &self.initial, true)),
+            steps.join(","),
+            as_json_string(&interaction_as_text(ctx, self.get_result(), true))
+        )
+    }
+
+    /**
+     * Draws the initial interaction and then the interaction obtained after each step
+     * in files "{file_name}_0.{ext}", "{file_name}_1.{ext}" etc. in the given folder,
+     * the extension being that of the kind of drawing.
+     * Returns the paths of these files in order.
+     **/
+    pub fn draw_steps_on_files(
+        &self,
+        ctx : &GeneralContext,
+        folder : &Path,
+        file_name : &str,
+        draw_kind : &InteractionDrawingKind
+    ) -> Vec<PathBuf> {
+        let interactions = std::iter::once(&self.initial).chain(self.steps.iter().map(|step| &step.after));
+        let mut paths = vec![];
+        for (int_id,int) in interactions.enumerate() {
+            let path = folder.join(format!("{}_{}.{}", file_name, int_id, draw_kind.get_file_extension()));
+            draw_interaction_on_file(&path, ctx, int, draw_kind);
+            paths.push(path);
+        }
+        paths
+    }
+
+}
+
+
+fn as_json_string(raw : &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in raw.chars() {
+        match c {
+            '"' => {
+                escaped.push_str("\\\"");
+            },
+            '\\' => {
+                escaped.push_str("\\\\");
+            },
+            '\n' => {
+                escaped.push_str("\\n");
+            },
+            '\t' => {
+                escaped.push_str("\\t");
+            },
+            '\r' => {
+                escaped.push_str("\\r");
+            },
+            // the other control characters must also be escaped in json strings
+            c if c < '\u{20}' => {
+                escaped.push_str(&format!("\\u{:04x}", c as u32));
+            },
+            _ => {
+                escaped.push(c);
+            }
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+
+
+/**
+ * The graph of all the derivations explored during a rewriting process.
+ * Steps which only move a term to the next phase do not carry a rule application.
+ **/
+pub(crate) struct RewritingDerivationGraph {
+    pub initial_node_id : u32,
+    pub interactions : HashMap<u32,Interaction>,
+    pub steps : HashMap<u32,Vec<(Option<RewriteRuleApplication>,u32)>>,
+    // the nodes which terms are final irreducible terms, in the order in which they were reached
+    pub final_nodes : Vec<u32>,
+    // the node which term is retained as the result of the rewriting
+    pub result_node_id : u32
+}
+
+impl RewritingDerivationGraph {
+
+    pub fn from_recorder(
+        recorder : &HibouRewritingDerivationRecorder,
+        global_state : &RewritingProcessState<HibouRewritableLangOperator>,
+        phases : &[RewritingPipelinePhase]
+    ) -> Self {
+        let initial_node_id = recorder.initial_node_id.unwrap();
+        let interactions = recorder.nodes.iter().map(
+            |(id,node)| (*id,Interaction::from_rewritable_term(&node.term))
+        ).collect();
+        let steps = recorder.steps.iter().map(
+            |(origin,targets)| {
+                let targets = targets.iter().map(
+                    |(step,target)| {
+                        let application = step.as_ref().map(
+                            |(concrete_phase_id,rule_index,position)| {
+                                let abstract_phase_id = global_state.concrete_phases.get(*concrete_phase_id).unwrap().model_abstract_phase_id;
+                                let rule = *phases.get(abstract_phase_id).unwrap().rules.get(*rule_index).unwrap();
+                                RewriteRuleApplication::new(rule, position.clone())
+                            }
+                        );
+                        (application,*target)
+                    }
+                ).collect();
+                (*origin,targets)
+            }
+        ).collect();
+        // a node is final if it has been fully explored without yielding any successor
+        // and if its term is irreducible within its phase
+        let mut final_nodes : Vec<u32> = recorder.nodes.iter().filter(
+            |(id,node)| {
+                !recorder.steps.contains_key(id)
+                && !recorder.filtered_nodes.contains(id)
+                && global_state.concrete_phases.get(node.concrete_rewrite_phase_index).unwrap().final_irreducible_terms.contains(&node.term)
+            }
+        ).map(|(id,_)| *id).collect();
+        final_nodes.sort();
+        // if the process has been truncated, there may not be any final node
+        // in which case the result is the term with which the last phase started
+        let result_node_id = match final_nodes.first() {
+            Some(node_id) => {
+                *node_id
+            },
+            None => {
+                let last_phase_id = global_state.concrete_phases.len() - 1;
+                let last_phase = global_state.concrete_phases.last().unwrap();
+                recorder.nodes.iter()
+                    .filter(|(_,node)| node.concrete_rewrite_phase_index == last_phase_id && last_phase.initial_input_terms.contains(&node.term))
+                    .map(|(id,_)| *id)
+                    .min()
+                    .unwrap_or(initial_node_id)
+            }
+        };
+        Self { initial_node_id, interactions, steps, final_nodes, result_node_id }
+    }
+
+    /**
+     * Returns, for each reachable node, its predecessor on a shortest path from the initial node
+     * and the step from that predecessor.
+     **/
+    fn get_predecessors(&self) -> HashMap<u32,(u32,&Option<RewriteRuleApplication>)> {
+        let mut predecessors = HashMap::new();
+        let mut queue = VecDeque::from([self.initial_node_id]);
+        while let Some(origin) = queue.pop_front() {
+            for (application,target) in self.steps.get(&origin).into_iter().flatten() {
+                if *target != self.initial_node_id && !predecessors.contains_key(target) {
+                    predecessors.insert(*target, (origin,application));
+                    queue.push_back(*target);
+                }
+            }
+        }
+        predecessors
+    }
+
+    /**
+     * Returns the rewrite steps on a shortest path from the initial node to the given node,
+     * as (the origin node, the rule application, the target node),
+     * leaving out the moves towards the next phases.
+     **/
+    fn get_steps_to(
+        &self, 
+        node_id : u32,
+        predecessors : &HashMap<u32,(u32,&Option<RewriteRuleApplication>)>
+    ) -> Vec<(u32,RewriteRuleApplication,u32)> {
+        let mut steps = vec![];
+        let mut current = node_id;
+        while let Some((origin,application)) = predecessors.get(&current) {
+            if let Some(application) = application {
+                steps.insert(0, (*origin,application.clone(),current));
+            }
+            current = *origin;
+        }
+        steps
+    }
+
+    /**
+     * Returns the derivation from the initial interaction to the result of the rewriting.
+     **/
+    pub fn get_derivation_to_result(&self) -> RewriteDerivation {
+        let predecessors = self.get_predecessors();
+        let steps = self.get_steps_to(self.result_node_id, &predecessors).into_iter().map(
+            |(origin,application,target)| RewriteDerivationStep::new(
+                application.rule.get_desc().to_owned(),
+                application.position,
+                self.interactions.get(&origin).unwrap().clone(),
+                self.interactions.get(&target).unwrap().clone()
+            )
+        ).collect();
+        RewriteDerivation::new(
+            self.interactions.get(&self.initial_node_id).unwrap().clone(),
+            steps
+        )
+    }
+
+    /**
+     * Returns the distinct final irreducible terms, each with a shortest rule path leading to it.
+     **/
+    pub fn get_irreducible_forms(&self) -> Vec<IrreducibleForm> {
+        let predecessors = self.get_predecessors();
+        let mut forms : Vec<IrreducibleForm> = vec![];
+        for node_id in &self.final_nodes {
+            let interaction = self.interactions.get(node_id).unwrap();
+            let rule_path : Vec<RewriteRuleApplication> = self.get_steps_to(*node_id, &predecessors)
+                .into_iter().map(|(_,application,_)| application).collect();
+            match forms.iter_mut().find(|form| &form.interaction == interaction) {
+                Some(form) => {
+                    if rule_path.len() < form.rule_path.len() {
+                        form.rule_path = rule_path;
+                    }
+                },
+                None => {
+                    forms.push(IrreducibleForm::new(interaction.clone(), rule_path));
+                }
+            }
+        }
+        forms
+    }
+
+    /**
+     * Returns, for each node, the set of final irreducible terms that can be reached from it.
+     * Those are identified by the final nodes from which they come from.
+     * As rewriting may cycle, the strongly connected components of the graph are computed (with Tarjan's algorithm)
+     * so that all the nodes of a component are given the same set.
+     **/
+    pub fn get_reachable_final_nodes(&self) -> HashMap<u32,BTreeSet<u32>> {
+        let mut tarjan = TarjanState::default();
+        let mut node_ids : Vec<u32> = self.interactions.keys().cloned().collect();
+        node_ids.sort();
+        for node_id in node_ids {
+            if !tarjan.indices.contains_key(&node_id) {
+                self.collect_reachable_final_nodes(node_id, &mut tarjan);
+            }
+        }
+        tarjan.reachable
+    }
+
+    fn collect_reachable_final_nodes(
+        &self,
+        node_id : u32,
+        tarjan : &mut TarjanState
+    ) {
+        let index = tarjan.indices.len() as u32;
+        tarjan.indices.insert(node_id, index);
+        tarjan.low_links.insert(node_id, index);
+        tarjan.stack.push(node_id);
+        tarjan.on_stack.insert(node_id);
+        for (_,target) in self.steps.get(&node_id).into_iter().flatten() {
+            if !tarjan.indices.contains_key(target) {
+                self.collect_reachable_final_nodes(*target, tarjan);
+                let low_link = *tarjan.low_links.get(&node_id).unwrap().min(tarjan.low_links.get(target).unwrap());
+                tarjan.low_links.insert(node_id, low_link);
+            } else if tarjan.on_stack.contains(target) {
+                let low_link = *tarjan.low_links.get(&node_id).unwrap().min(tarjan.indices.get(target).unwrap());
+                tarjan.low_links.insert(node_id, low_link);
+            }
+        }
+        // ***
+        if tarjan.low_links.get(&node_id) == tarjan.indices.get(&node_id) {
+            // the node is the root of a strongly connected component
+            // all the components reachable from it have already been processed
+            let mut component = vec![];
+            while let Some(member) = tarjan.stack.pop() {
+                tarjan.on_stack.remove(&member);
+                component.push(member);
+                if member == node_id {
+                    break;
+                }
+            }
+            let mut got = BTreeSet::new();
+            for member in &component {
+                if self.final_nodes.contains(member) {
+                    got.insert(*member);
+                }
+                for (_,target) in self.steps.get(member).into_iter().flatten() {
+                    if let Some(from_target) = tarjan.reachable.get(target) {
+                        got.extend(from_target.iter().cloned());
+                    }
+                }
+            }
+            for member in component {
+                tarjan.reachable.insert(member, got.clone());
+            }
+        }
+    }
+
+}
+
+
+
+#[derive(Default)]
+struct TarjanState {
+    // the order in which the nodes are visited
+    indices : HashMap<u32,u32>,
+    low_links : HashMap<u32,u32>,
+    stack : Vec<u32>,
+    on_stack : HashSet<u32>,
+    // the final nodes reachable from the nodes which components have been fully processed
+    reachable : HashMap<u32,BTreeSet<u32>>
+}
@@ -15,14 +15,18 @@ limitations under the License.
 */
 
 
+use std::fs;
 use std::path::Path;
+use std::process::Command;
 
 use graphviz_dot_builder::item::node::style::{GraphvizNodeStyle, GraphvizNodeStyleItem, GvNodeShape};
+use graphviz_dot_builder::traits::DotTranslatable;
 use simple_term_rewriter::core::terms::conversion::to_rewritable_term::FromDomainSpecificTermToRewritableTerm;
 use simple_term_rewriter::draw_term::TermDrawingContext;
-use simple_term_rewriter::draw_term::draw_term_tree_with_graphviz;
+use simple_term_rewriter::draw_term::term_gv_repr;
 
 use crate::core::{general_context::GeneralContext, syntax::interaction::{Interaction, LoopKind}};
+use crate::error::HibouError;
 
 use super::lang::HibouRewritableLangOperator;
 
@@ -36,14 +40,43 @@ pub(crate) fn draw_interaction_as_term_tree_on_file(
     ctx : &GeneralContext,
     int : &Interaction
 ) {
-    let temp_file_path = format!("{}_temp.dot",file_path.to_str().unwrap());
+    let _ = try_draw_interaction_as_term_tree_on_file(file_path, ctx, int);
+}
+
+/**
+ * Same as *draw_interaction_as_term_tree_on_file* but reports the errors
+ * that occur when writing the dot file or when running graphviz.
+ * The output format of graphviz is given by the extension of the file, png by default.
+ **/
+pub(crate) fn try_draw_interaction_as_term_tree_on_file(
+    file_path : &Path,
+    ctx : &GeneralContext,
+    int : &Interaction
+) -> Result<(),HibouError> {
+    let temp_file_path = match file_path.to_str() {
+        Some(path) => {
+            format!("{}_temp.dot",path)
+        },
+        None => {
+            return Err(HibouError::Io(format!("path {:?} is not valid unicode", file_path)));
+        }
+    };
     // ***
-    draw_term_tree_with_graphviz::<HibouRewritableLangOperator,GeneralContext>(
-        ctx,
-        &int.to_rewritable_term(),
-        &Path::new(&temp_file_path),
-        file_path
-    );
+    let digraph = term_gv_repr::<HibouRewritableLangOperator,GeneralContext>(ctx, &int.to_rewritable_term());
+    fs::write(&temp_file_path, digraph.to_dot_string())?;
+    let output_format = file_path.extension().and_then(|ext| ext.to_str()).unwrap_or("png");
+    let output = Command::new("dot")
+        .arg(format!("-T{}", output_format))
+        .arg(&temp_file_path)
+        .arg("-o")
+        .arg(file_path)
+        .output()
+        .map_err(|e| HibouError::Io(format!("could not run graphviz: {}", e)))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(HibouError::Io(format!("graphviz failed: {}", String::from_utf8_lossy(&output.stderr).trim())))
+    }
 }
 
 
@@ -104,6 +137,13 @@ impl TermDrawingContext<HibouRewritableLangOperator> for GeneralContext {
             HibouRewritableLangOperator::And => {
                 "and".to_owned()
             },
+            HibouRewritableLangOperator::Sync(sync_acts) => {
+                let acts : Vec<String> = sync_acts.iter().map(|act| self.trace_action_as_text(act)).collect();
+                format!(
+                    "sync({})",
+                    acts.join(",")
+                )
+            },
         };
         vec![
             GraphvizNodeStyleItem::Shape(GvNodeShape::Rectangle),
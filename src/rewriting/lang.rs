@@ -17,6 +17,7 @@ limitations under the License.
 
 use simple_term_rewriter::core::terms::term::RewritableLanguageOperatorSymbol;
 
+use crate::core::semantics::trace_action::TraceAction;
 use crate::core::syntax::action::{EmissionAction, ReceptionAction};
 use crate::core::syntax::interaction::LoopKind;
 
@@ -29,7 +30,8 @@ pub enum HibouRewritableLangOperator {
     Alt,
     CoReg(Vec<usize>),
     Loop(LoopKind),
-    And
+    And,
+    Sync(Vec<TraceAction>)
 }
 
 impl RewritableLanguageOperatorSymbol for HibouRewritableLangOperator {}
@@ -63,6 +65,7 @@ impl HibouRewritableLangOperator {
             HibouRewritableLangOperator::CoReg(_) => {2}
             HibouRewritableLangOperator::Loop(_) => {1}
             HibouRewritableLangOperator::And => {2}
+            HibouRewritableLangOperator::Sync(_) => {2}
         }
     }
 
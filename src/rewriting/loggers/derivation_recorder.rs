@@ -0,0 +1,150 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+
+use graph_process_manager_core::process::filter::GenericFiltersManager;
+use graph_process_manager_core::process::logger::AbstractProcessLogger;
+use graph_process_manager_core::queue::priorities::GenericProcessPriorities;
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+use simple_term_rewriter::rewriting_process::conf::RewriteConfig;
+use simple_term_rewriter::rewriting_process::context::RewritingProcessContextAndParameterization;
+use simple_term_rewriter::rewriting_process::filtration::RewritingFiltrationResult;
+use simple_term_rewriter::rewriting_process::node::RewriteNodeKind;
+use simple_term_rewriter::rewriting_process::priorities::RewritePriorities;
+use simple_term_rewriter::rewriting_process::state::RewritingProcessState;
+use simple_term_rewriter::rewriting_process::step::RewriteStepKind;
+
+use crate::rewriting::lang::HibouRewritableLangOperator;
+
+
+
+
+/**
+ * A rewrite step as it was recorded:
+ * - either the application of the rule which index is given within the rules of the concrete phase which index is given,
+ *   at the position which coordinates are given
+ * - or the move of the term towards the next phase (None)
+ **/
+pub type HibouRecordedRewriteStep = Option<(usize,usize,Vec<usize>)>;
+
+
+
+/**
+ * Silently records the nodes and steps of a rewriting process
+ * so that the derivations may be analyzed once the process has terminated.
+ **/
+#[derive(Default)]
+pub struct HibouRewritingDerivationRecorder {
+    // the first node to be logged is the initial node
+    pub initial_node_id : Option<u32>,
+    pub nodes : HashMap<u32,RewriteNodeKind<HibouRewritableLangOperator>>,
+    // all the steps, including those towards already memoized nodes
+    pub steps : HashMap<u32,Vec<(HibouRecordedRewriteStep,u32)>>,
+    // the nodes from which the exploration has been cut by a filter
+    pub filtered_nodes : HashSet<u32>
+}
+
+impl HibouRewritingDerivationRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl AbstractProcessLogger<RewriteConfig<HibouRewritableLangOperator>> for HibouRewritingDerivationRecorder {
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(
+        &mut self,
+        _context_and_param : &RewritingProcessContextAndParameterization<HibouRewritableLangOperator>,
+        _strategy : &QueueSearchStrategy,
+        _priorities : &GenericProcessPriorities<RewritePriorities>,
+        _filters_manager : &GenericFiltersManager<RewriteConfig<HibouRewritableLangOperator>>,
+        _initial_global_state : &RewritingProcessState<HibouRewritableLangOperator>,
+        _use_memoization : bool,
+    ) {}
+
+    fn log_new_node(
+        &mut self,
+        _context_and_param : &RewritingProcessContextAndParameterization<HibouRewritableLangOperator>,
+        new_node_id : u32,
+        new_node : &RewriteNodeKind<HibouRewritableLangOperator>
+    ) {
+        if self.initial_node_id.is_none() {
+            self.initial_node_id = Some(new_node_id);
+        }
+        self.nodes.insert(new_node_id, new_node.clone());
+    }
+
+    fn log_new_step(
+        &mut self,
+        _context_and_param : &RewritingProcessContextAndParameterization<HibouRewritableLangOperator>,
+        origin_node_id : u32,
+        step : &RewriteStepKind<HibouRewritableLangOperator>,
+        target_node_id : u32,
+        _target_node : &RewriteNodeKind<HibouRewritableLangOperator>
+    ) {
+        let recorded = match step {
+            RewriteStepKind::TransformInSamePhase(transfo) => {
+                Some(
+                    (
+                        transfo.phase_index,
+                        transfo.rule_index_in_phase,
+                        transfo.position.get_absolute_coordinates_from_root().to_vec()
+                    )
+                )
+            },
+            RewriteStepKind::GoToSuccessorPhase(_) => {
+                None
+            }
+        };
+        self.steps.entry(origin_node_id).or_default().push((recorded,target_node_id));
+    }
+
+    fn log_notify_last_child_step_of_node_processed(
+        &mut self,
+        _context_and_param : &RewritingProcessContextAndParameterization<HibouRewritableLangOperator>,
+        _parent_node_id : u32
+    ) {}
+
+    fn log_notify_node_without_children(
+        &mut self,
+        _context_and_param : &RewritingProcessContextAndParameterization<HibouRewritableLangOperator>,
+        _node_id : u32
+    ) {}
+
+    fn log_filtered(
+        &mut self,
+        _context_and_param : &RewritingProcessContextAndParameterization<HibouRewritableLangOperator>,
+        parent_node_id : u32,
+        _filtration_result_id : u32,
+        _filtration_result : &RewritingFiltrationResult
+    ) {
+        self.filtered_nodes.insert(parent_node_id);
+    }
+
+    fn log_terminate_process(
+        &mut self,
+        _context_and_param : &RewritingProcessContextAndParameterization<HibouRewritableLangOperator>,
+        _global_state : &RewritingProcessState<HibouRewritableLangOperator>
+    ) {}
+
+}
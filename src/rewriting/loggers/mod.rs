@@ -14,4 +14,5 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-pub mod glog;
\ No newline at end of file
+pub mod glog;
+pub mod derivation_recorder;
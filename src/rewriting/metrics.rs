@@ -40,7 +40,8 @@ pub enum InteractionTermSymbolMetrics {
     LoopWeak,
     AnyLoop,
     Alt,
-    And
+    And,
+    Sync
 }
 
 impl std::fmt::Display for InteractionTermSymbolMetrics {
@@ -85,6 +86,9 @@ impl std::fmt::Display for InteractionTermSymbolMetrics {
             InteractionTermSymbolMetrics::And => {
                 write!(f,"And")
             },
+            InteractionTermSymbolMetrics::Sync => {
+                write!(f,"Sync")
+            },
         }
     }
 }
@@ -171,6 +175,11 @@ impl TermSymbolMetric<HibouRewritableLangOperator> for InteractionTermSymbolMetr
                     InteractionTermSymbolMetrics::And,
                 }
             },
+            HibouRewritableLangOperator::Sync(_) => {
+                hashset!{
+                    InteractionTermSymbolMetrics::Sync,
+                }
+            },
         }
     }
 }
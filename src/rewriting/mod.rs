@@ -18,7 +18,7 @@ limitations under the License.
 
 
 pub(in crate::rewriting) mod conversion;
-pub(in crate::rewriting) mod rules;
+pub mod rules;
 
 pub(crate) mod draw_as_term;
 pub(crate) mod lang;
@@ -28,7 +28,11 @@ pub(in crate::rewriting) mod loggers;
 
 
 pub mod canonize;
+pub mod pipeline;
+pub mod derivation;
+pub mod confluence;
 pub mod metrics;
+pub mod soundness;
 
 
 
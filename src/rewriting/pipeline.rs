@@ -0,0 +1,496 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use graph_process_manager_core::process::filter::{AbstractStepFilter, GenericFiltersManager};
+use graph_process_manager_core::process::logger::AbstractProcessLogger;
+use graph_process_manager_core::process::manager::GenericProcessManager;
+use graph_process_manager_core::queue::priorities::GenericProcessPriorities;
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+use simple_term_rewriter::core::rule::RewriteRule;
+use simple_term_rewriter::core::terms::position::PositionInLanguageTerm;
+use simple_term_rewriter::core::terms::term::LanguageTerm;
+use simple_term_rewriter::core::terms::conversion::from_rewritable_term::FromRewritableTermToDomainSpecificTerm;
+use simple_term_rewriter::core::terms::conversion::to_rewritable_term::FromDomainSpecificTermToRewritableTerm;
+use simple_term_rewriter::rewriting_process::conf::RewriteConfig;
+use simple_term_rewriter::rewriting_process::context::{AbstractRewritingPhase, RewritingProcessContextAndParameterization};
+use simple_term_rewriter::rewriting_process::filter::RewriteStepFilter;
+use simple_term_rewriter::rewriting_process::node::RewriteNodeKind;
+use simple_term_rewriter::rewriting_process::priorities::RewritePriorities;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::syntax::interaction::Interaction;
+use crate::error::HibouError;
+use crate::interfaces::HibouGraphvizLoggerParam;
+
+use super::canonize::get_graphviz_logger_from_param;
+use super::derivation::{IrreducibleForm, RewriteDerivation, RewritingDerivationGraph};
+use super::loggers::derivation_recorder::HibouRewritingDerivationRecorder;
+use super::lang::HibouRewritableLangOperator;
+use super::rules::high_level_hibou_rewrite_rules::HighLevelHibouRewriteRules;
+
+
+
+/**
+ * A rewriting phase applies its rules until an irreducible term is reached.
+ * The irreducible term is then forwarded to the phase which index is given
+ * depending on whether or not the phase changed the term.
+ * If there is no such next phase, the rewriting ends.
+ * The number of rule applications in the phase may be bounded,
+ * this bound being shared by all the times the phase is entered.
+ * Once it is reached, the terms of the phase are considered irreducible and are forwarded to the next phase.
+ * The applications are counted along the single derivation that is explored when only one rewrite step
+ * is kept from each term, hence such a bound is rejected when all the rewrite steps are explored.
+ **/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RewritingPipelinePhase {
+    pub rules : Vec<HighLevelHibouRewriteRules>,
+    pub next_phase_on_changed : Option<usize>,
+    pub next_phase_on_unchanged : Option<usize>,
+    pub max_applications : Option<u32>
+}
+
+impl RewritingPipelinePhase {
+    pub fn new(
+        rules : Vec<HighLevelHibouRewriteRules>,
+        next_phase_on_changed : Option<usize>,
+        next_phase_on_unchanged : Option<usize>
+    ) -> Self {
+        Self { rules, next_phase_on_changed, next_phase_on_unchanged, max_applications : None }
+    }
+
+    pub fn max_applications(mut self, max_applications : Option<u32>) -> Self {
+        self.max_applications = max_applications;
+        self
+    }
+
+    fn get_low_level_rewrite_rules(&self) -> Vec<Box<dyn RewriteRule<HibouRewritableLangOperator>>> {
+        match self.max_applications {
+            None => {
+                self.rules.iter().map(|rule| rule.get_low_level_rewrite_rule()).collect()
+            },
+            Some(max_applications) => {
+                let remaining_applications = Rc::new(Cell::new(max_applications));
+                self.rules.iter().map(
+                    |rule| Box::new(
+                        PhaseBoundedRewriteRule{
+                            rule : rule.get_low_level_rewrite_rule(),
+                            remaining_applications : remaining_applications.clone()
+                        }
+                    ) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
+                ).collect()
+            }
+        }
+    }
+}
+
+
+
+/**
+ * A rule of a phase which number of applications is bounded.
+ * All the rules of the phase share the same count of remaining applications.
+ * Each successful application yields a rewrite step.
+ * The count is shared by all the branches of the exploration,
+ * hence it only bounds the length of a derivation if a single rewrite step is kept from each term.
+ **/
+struct PhaseBoundedRewriteRule {
+    rule : Box<dyn RewriteRule<HibouRewritableLangOperator>>,
+    remaining_applications : Rc<Cell<u32>>
+}
+
+impl RewriteRule<HibouRewritableLangOperator> for PhaseBoundedRewriteRule {
+    fn get_desc(&self) -> String {
+        self.rule.get_desc()
+    }
+
+    fn try_apply(
+        &self,
+        term : &LanguageTerm<HibouRewritableLangOperator>,
+        context_term : &LanguageTerm<HibouRewritableLangOperator>,
+        position_in_context_term : &PositionInLanguageTerm
+    ) -> Option<LanguageTerm<HibouRewritableLangOperator>> {
+        let remaining = self.remaining_applications.get();
+        if remaining == 0 {
+            return None;
+        }
+        let result = self.rule.try_apply(term, context_term, position_in_context_term);
+        if result.is_some() {
+            self.remaining_applications.set(remaining - 1);
+        }
+        result
+    }
+}
+
+
+
+/**
+ * A configurable rewriting process over interactions.
+ * It starts with the first phase.
+ * The number of rewrite steps may be bounded, in which case the result may be
+ * the term with which the last concretized phase started, if it could not terminate.
+ **/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RewritingPipeline {
+    phases : Vec<RewritingPipelinePhase>,
+    keep_only_one : bool,
+    max_rewrite_steps : Option<u32>
+}
+
+impl Default for RewritingPipeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RewritingPipeline {
+
+    pub fn new() -> Self {
+        Self { phases : vec![], keep_only_one : true, max_rewrite_steps : None }
+    }
+
+    pub fn with_phase(mut self, phase : RewritingPipelinePhase) -> Self {
+        self.phases.push(phase);
+        self
+    }
+
+    // whether or not only one rewrite step is explored from each term
+    pub fn keep_only_one(mut self, keep_only_one : bool) -> Self {
+        self.keep_only_one = keep_only_one;
+        self
+    }
+
+    pub fn max_rewrite_steps(mut self, max_rewrite_steps : Option<u32>) -> Self {
+        self.max_rewrite_steps = max_rewrite_steps;
+        self
+    }
+
+    pub fn get_phases(&self) -> &Vec<RewritingPipelinePhase> {
+        &self.phases
+    }
+
+    // ********** ********** ********** ********** ********** ********** **********
+    // ********** ********** ********** ********** ********** ********** **********
+    // ********** ********** ********** ********** ********** ********** **********
+
+    /**
+     * The rules used by the presets, in the order in which they are tried.
+     * With "coregion_reordering", reordering and minimizing coregions modulo AC
+     * replaces "ReorderSubInteractionsUnderCoreg" and "CoregionMinimizationBasic".
+     **/
+    fn get_rules(
+        coreg_simplifications : bool,
+        coregion_reordering : bool,
+        kleene_desequencing : bool
+    ) -> Vec<HighLevelHibouRewriteRules> {
+        let mut rules = vec![
+            HighLevelHibouRewriteRules::StrictFlushRight,
+            HighLevelHibouRewriteRules::AltAndCoregFlushRight,
+            HighLevelHibouRewriteRules::ReorderSubInteractionsUnderAlt,
+        ];
+        if !coregion_reordering {
+            rules.push(HighLevelHibouRewriteRules::ReorderSubInteractionsUnderCoreg);
+        }
+        rules.extend(
+            vec![
+                HighLevelHibouRewriteRules::EpsilonFixpoint,
+                HighLevelHibouRewriteRules::EpsilonNeutral,
+                HighLevelHibouRewriteRules::KleeneNesting,
+                HighLevelHibouRewriteRules::KleeneTighteningModuloAC,
+                HighLevelHibouRewriteRules::KleeneRolling,
+                HighLevelHibouRewriteRules::StrictnessRelaxationBinary,
+                HighLevelHibouRewriteRules::StrictnessRelaxationUnary,
+                HighLevelHibouRewriteRules::BasicAltDeduplication,
+                HighLevelHibouRewriteRules::SequencingCompatibilityLeft,
+                HighLevelHibouRewriteRules::SequencingCompatibilityRight,
+            ]
+        );
+        if kleene_desequencing {
+            rules.push(HighLevelHibouRewriteRules::KleeneDesequencing);
+        }
+        if coregion_reordering {
+            rules.extend(
+                vec![
+                    HighLevelHibouRewriteRules::CoregionReorderAndMinimizeModuloAC,
+                    HighLevelHibouRewriteRules::CoregionMinimizationKleene,
+                ]
+            );
+        } else if coreg_simplifications {
+            rules.extend(
+                vec![
+                    HighLevelHibouRewriteRules::CoregionMinimizationBasic,
+                    HighLevelHibouRewriteRules::CoregionMinimizationKleene,
+                ]
+            );
+        }
+        rules
+    }
+
+    /**
+     * Rules which simplify an interaction without changing its structure much
+     * i.e. neither factorizing nor de-factorizing.
+     **/
+    pub fn get_simplification_rules(coreg_simplifications : bool) -> Vec<HighLevelHibouRewriteRules> {
+        Self::get_rules(coreg_simplifications, false, false)
+    }
+
+    /**
+     * The simplification rules and those which are always applied during canonization.
+     * KleeneDesequencing relies on inclusion checking and is hence comparatively expensive.
+     **/
+    pub fn get_base_canonization_rules(coreg_simplifications : bool) -> Vec<HighLevelHibouRewriteRules> {
+        Self::get_rules(coreg_simplifications, false, true)
+    }
+
+    /**
+     * A single phase with only the simplification rules, which is cheap enough
+     * for e.g. simplifying interactions before displaying them.
+     **/
+    pub fn simplify_only(coreg_simplifications : bool) -> Self {
+        Self::new().with_phase(
+            RewritingPipelinePhase::new(
+                Self::get_simplification_rules(coreg_simplifications),
+                None,
+                None
+            )
+        )
+    }
+
+    /**
+     * The three phases canonization:
+     * - de-factorization
+     * - right factorization
+     * - left factorization, after which we go back to right factorization if the term has changed
+     **/
+    pub fn full_canonical(coreg_simplifications : bool) -> Self {
+        Self::full_canonical_from_base_rules(Self::get_base_canonization_rules(coreg_simplifications))
+    }
+
+    /**
+     * The three phases canonization in which the coregions are reordered and minimized modulo AC
+     * instead of under each binary operator separately.
+     * Interactions which only differ in the order of independent sub-interactions
+     * (e.g. exchanges between distinct lifelines under a *seq*) then have the same canonical form.
+     **/
+    pub fn full_canonical_with_coregion_reordering() -> Self {
+        Self::full_canonical_from_base_rules(Self::get_rules(true, true, true))
+    }
+
+    fn full_canonical_from_base_rules(base_rules : Vec<HighLevelHibouRewriteRules>) -> Self {
+        let mut phase1 = base_rules.clone();
+        phase1.extend(
+            vec![
+                HighLevelHibouRewriteRules::DeFactorizeLeft,
+                HighLevelHibouRewriteRules::DeFactorizeRight
+            ]
+        );
+        let mut phase2 = base_rules.clone();
+        phase2.push(HighLevelHibouRewriteRules::FactorizeRight);
+        let mut phase3 = base_rules;
+        phase3.push(HighLevelHibouRewriteRules::FactorizeLeft);
+        // ***
+        Self::new()
+            .with_phase(RewritingPipelinePhase::new(phase1, Some(1), Some(1)))
+            .with_phase(RewritingPipelinePhase::new(phase2, Some(2), Some(2)))
+            .with_phase(RewritingPipelinePhase::new(phase3, Some(1), None))
+    }
+
+    /**
+     * The three phases canonization followed by a phase which removes the summands of alternatives
+     * that are included in other summands.
+     * If this removes summands, we go back to right factorization.
+     * Inclusion checking is costly hence this is not done by default.
+     **/
+    pub fn full_canonical_with_summand_inclusion(coreg_simplifications : bool) -> Self {
+        let mut pipeline = Self::full_canonical(coreg_simplifications);
+        let summand_inclusion_phase_id = pipeline.phases.len();
+        pipeline.phases.last_mut().unwrap().next_phase_on_unchanged = Some(summand_inclusion_phase_id);
+        let mut phase4 = Self::get_base_canonization_rules(coreg_simplifications);
+        phase4.push(HighLevelHibouRewriteRules::SummandInclusionModuloAC);
+        pipeline.with_phase(RewritingPipelinePhase::new(phase4, Some(1), None))
+    }
+
+    // ********** ********** ********** ********** ********** ********** **********
+    // ********** ********** ********** ********** ********** ********** **********
+    // ********** ********** ********** ********** ********** ********** **********
+
+    /**
+     * The applications of the rules of a bounded phase are counted across all the explored derivations,
+     * which would make the irreducible forms that are reached depend on the order of the exploration.
+     **/
+    fn check_bounds_apply_to_a_single_derivation(&self, keep_only_one : bool) -> Result<(),HibouError> {
+        if !keep_only_one && self.phases.iter().any(|phase| phase.max_applications.is_some()) {
+            return Err(
+                HibouError::InvalidConfiguration(
+                    "the number of rule applications of a phase cannot be bounded when all the rewrite steps are explored".to_owned()
+                )
+            );
+        }
+        Ok(())
+    }
+
+    fn run_rewriting_process(
+        &self,
+        int : &Interaction,
+        keep_only_one : bool,
+        loggers : Vec<Box< dyn AbstractProcessLogger<RewriteConfig<HibouRewritableLangOperator>>>>
+    ) -> GenericProcessManager<RewriteConfig<HibouRewritableLangOperator>> {
+        let phases = self.phases.iter().map(
+            |phase| AbstractRewritingPhase::<HibouRewritableLangOperator>::new(
+                phase.get_low_level_rewrite_rules(),
+                phase.next_phase_on_changed,
+                phase.next_phase_on_unchanged
+            )
+        ).collect();
+
+        let context_and_param = RewritingProcessContextAndParameterization::new(
+            phases,
+            keep_only_one
+        );
+
+        let mut step_filters : Vec<Box<dyn AbstractStepFilter<RewriteConfig<HibouRewritableLangOperator>>>> = vec![];
+        if let Some(max_rewrite_steps) = self.max_rewrite_steps {
+            step_filters.push(Box::new(RewriteStepFilter::MaxNodeNumber(max_rewrite_steps)));
+        }
+
+        // ***
+
+        let mut manager : GenericProcessManager<RewriteConfig<HibouRewritableLangOperator>> = GenericProcessManager::new(
+            context_and_param,
+            QueueSearchStrategy::DFS,
+            GenericProcessPriorities::new(RewritePriorities{},false),
+            GenericFiltersManager::new(
+                vec![], 
+                vec![], 
+                step_filters
+            ),
+            loggers,
+            true,
+            RewriteNodeKind::new(
+                int.to_rewritable_term(),
+                0
+            )
+        );
+
+        let _ = manager.start_process();
+        manager
+    }
+
+    /**
+     * Fails if the number of rule applications of a phase is bounded while all the rewrite steps are explored.
+     **/
+    pub fn rewrite(
+        &self,
+        int : &Interaction,
+        graphviz_param : Option<(&GeneralContext,&str,&HibouGraphvizLoggerParam)>
+    ) -> Result<Interaction,HibouError> {
+        if self.phases.is_empty() {
+            return Ok(int.clone());
+        }
+        self.check_bounds_apply_to_a_single_derivation(self.keep_only_one)?;
+
+        let loggers = match graphviz_param {
+            None => {
+                vec![]
+            },
+            Some((gen_ctx,fname,gv_log_param)) => {
+                get_graphviz_logger_from_param(gen_ctx, gv_log_param, fname)
+            }
+        };
+
+        let manager = self.run_rewriting_process(int, self.keep_only_one, loggers);
+
+        // if several irreducible terms are found, the first one is kept
+        // (see "rewrite_to_all_irreducible_forms" to get all of them)
+        let last_phase = manager.global_state.concrete_phases.last().unwrap();
+        // if the process has been truncated, the last phase may not have reached any irreducible term
+        match last_phase.final_irreducible_terms.iter().chain(last_phase.initial_input_terms.iter()).next() {
+            Some(result) => {
+                Ok(Interaction::from_rewritable_term(result))
+            },
+            None => {
+                Ok(int.clone())
+            }
+        }
+    }
+
+    /**
+     * Runs the rewriting process while recording all the derivations.
+     **/
+    pub(in crate::rewriting) fn explore_derivations(
+        &self,
+        int : &Interaction,
+        keep_only_one : bool,
+        graphviz_param : Option<(&GeneralContext,&str,&HibouGraphvizLoggerParam)>
+    ) -> RewritingDerivationGraph {
+        let mut loggers = match graphviz_param {
+            None => {
+                vec![]
+            },
+            Some((gen_ctx,fname,gv_log_param)) => {
+                get_graphviz_logger_from_param(gen_ctx, gv_log_param, fname)
+            }
+        };
+        let recorder_id = loggers.len();
+        loggers.push(Box::new(HibouRewritingDerivationRecorder::new()));
+
+        let manager = self.run_rewriting_process(int, keep_only_one, loggers);
+
+        let recorder = manager.get_logger(recorder_id).unwrap()
+            .as_any().downcast_ref::<HibouRewritingDerivationRecorder>().unwrap();
+        RewritingDerivationGraph::from_recorder(recorder, &manager.global_state, &self.phases)
+    }
+
+    /**
+     * Rewrites the interaction as "rewrite" does and explains the result
+     * with the rewrite steps that lead to it.
+     **/
+    pub fn rewrite_with_derivation(
+        &self,
+        int : &Interaction,
+        graphviz_param : Option<(&GeneralContext,&str,&HibouGraphvizLoggerParam)>
+    ) -> Result<RewriteDerivation,HibouError> {
+        if self.phases.is_empty() {
+            return Ok(RewriteDerivation::new(int.clone(), vec![]));
+        }
+        self.check_bounds_apply_to_a_single_derivation(self.keep_only_one)?;
+        Ok(self.explore_derivations(int, self.keep_only_one, graphviz_param).get_derivation_to_result())
+    }
+
+    /**
+     * Explores all the possible rewrite steps from each term, regardless of "keep_only_one",
+     * and returns all the final irreducible terms that can be reached,
+     * each with a shortest sequence of rule applications which leads to it.
+     * Several irreducible forms witness the non-confluence of the pipeline on this interaction
+     * (see "find_critical_pairs" to locate where the rewriting diverges).
+     * If the number of rewrite steps is bounded, only the irreducible terms reached before the bound are returned.
+     * The number of rule applications of the phases cannot be bounded.
+     **/
+    pub fn rewrite_to_all_irreducible_forms(
+        &self,
+        int : &Interaction,
+        graphviz_param : Option<(&GeneralContext,&str,&HibouGraphvizLoggerParam)>
+    ) -> Result<Vec<IrreducibleForm>,HibouError> {
+        if self.phases.is_empty() {
+            return Ok(vec![IrreducibleForm::new(int.clone(), vec![])]);
+        }
+        self.check_bounds_apply_to_a_single_derivation(false)?;
+        Ok(self.explore_derivations(int, false, graphviz_param).get_irreducible_forms())
+    }
+
+}
@@ -26,12 +26,14 @@ use crate::rewriting::rules::transfos_canonize::distributivity_checker::HibouDis
 
 use super::transfos_canonize::basic_alt_deduplication::HibouAltDeduplicator;
 use super::transfos_canonize::coregion_minimization::HibouCoregionMinimizer;
+use super::transfos_canonize::coregion_reordering::HibouCoregionReordererAndMinimizer;
 use super::transfos_canonize::flush_right::HibouAssocCheckerToFlushAltCoregRight;
 use super::transfos_canonize::kleene_desequencing::HibouKleeneDesequencer;
 use super::transfos_canonize::kleene_tightening::HibouKleeneTightener;
 use super::transfos_canonize::sequencing_compatibility::left_seq_compat::HibouLeftSequencingCompatibilizer;
 use super::transfos_canonize::sequencing_compatibility::right_seq_compat::HibouRightSequencingCompatibilizer;
 use super::transfos_canonize::strictness_relaxation::HibouStrictnessRelaxer;
+use super::transfos_canonize::summand_inclusion::HibouAltSummandIncluder;
 use super::transfos_canonize::empty_interaction_simplifier::HibouEmptyInteractionSimplifier;
 use super::transfos_canonize::kleene_nesting::HibouKleeneNestingSimplifier;
 use super::transfos_canonize::kleene_rolling::HibouKleeneRoller;
@@ -39,6 +41,7 @@ use super::transfos_canonize::kleene_rolling::HibouKleeneRoller;
 
 
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum HighLevelHibouRewriteRules {
 
     StrictFlushRight,
@@ -48,10 +51,7 @@ pub enum HighLevelHibouRewriteRules {
 
     ReorderSubInteractionsUnderCoreg,
 
-    // TODO: this requires further work : modulo coreg op AC: 
-    // make a graph of dependencies between sub-terms under the same coreg op
-    // then reorder according t
-    //CoregionReorderAndMinimizeModuloAC, 
+    CoregionReorderAndMinimizeModuloAC,
 
     CoregionMinimizationBasic,
     CoregionMinimizationKleene,
@@ -77,87 +77,198 @@ pub enum HighLevelHibouRewriteRules {
 
 
     BasicAltDeduplication,
-    //SummandInclusionModuloAC
+    SummandInclusionModuloAC
 }
 
 
 impl HighLevelHibouRewriteRules {
 
-    pub fn get_low_level_rewrite_rule(&self) -> Box<dyn RewriteRule<HibouRewritableLangOperator>> {
+    pub fn get_all_rules() -> Vec<HighLevelHibouRewriteRules> {
+        vec![
+            HighLevelHibouRewriteRules::StrictFlushRight,
+            HighLevelHibouRewriteRules::AltAndCoregFlushRight,
+            HighLevelHibouRewriteRules::ReorderSubInteractionsUnderAlt,
+            HighLevelHibouRewriteRules::ReorderSubInteractionsUnderCoreg,
+            HighLevelHibouRewriteRules::CoregionReorderAndMinimizeModuloAC,
+            HighLevelHibouRewriteRules::CoregionMinimizationBasic,
+            HighLevelHibouRewriteRules::CoregionMinimizationKleene,
+            HighLevelHibouRewriteRules::FactorizeLeft,
+            HighLevelHibouRewriteRules::FactorizeRight,
+            HighLevelHibouRewriteRules::DeFactorizeLeft,
+            HighLevelHibouRewriteRules::DeFactorizeRight,
+            HighLevelHibouRewriteRules::EpsilonFixpoint,
+            HighLevelHibouRewriteRules::EpsilonNeutral,
+            HighLevelHibouRewriteRules::KleeneDesequencing,
+            HighLevelHibouRewriteRules::KleeneNesting,
+            HighLevelHibouRewriteRules::KleeneTighteningModuloAC,
+            HighLevelHibouRewriteRules::KleeneRolling,
+            HighLevelHibouRewriteRules::StrictnessRelaxationBinary,
+            HighLevelHibouRewriteRules::StrictnessRelaxationUnary,
+            HighLevelHibouRewriteRules::SequencingCompatibilityRight,
+            HighLevelHibouRewriteRules::SequencingCompatibilityLeft,
+            HighLevelHibouRewriteRules::BasicAltDeduplication,
+            HighLevelHibouRewriteRules::SummandInclusionModuloAC
+        ]
+    }
+
+    /**
+     * The description of the rule as it appears in the rewriting process.
+     **/
+    pub fn get_desc(&self) -> &'static str {
+        match self {
+            HighLevelHibouRewriteRules::StrictFlushRight => {
+                "StrictFlushRight"
+            },
+            HighLevelHibouRewriteRules::AltAndCoregFlushRight => {
+                "AltAndCoregFlushRight"
+            },
+            HighLevelHibouRewriteRules::ReorderSubInteractionsUnderAlt => {
+                "ReorderSubInteractionsUnderAlt"
+            },
+            HighLevelHibouRewriteRules::ReorderSubInteractionsUnderCoreg => {
+                "ReorderSubInteractionsUnderCoreg"
+            },
+            HighLevelHibouRewriteRules::CoregionReorderAndMinimizeModuloAC => {
+                "CoregionReorderAndMinimizeModuloAC"
+            },
+            HighLevelHibouRewriteRules::CoregionMinimizationBasic => {
+                "CoregionMinimizationBasic"
+            },
+            HighLevelHibouRewriteRules::CoregionMinimizationKleene => {
+                "CoregionMinimizationKleene"
+            },
+            HighLevelHibouRewriteRules::EpsilonFixpoint => {
+                "EpsilonFixpoint"
+            },
+            HighLevelHibouRewriteRules::EpsilonNeutral => {
+                "EpsilonNeutral"
+            },
+            HighLevelHibouRewriteRules::FactorizeLeft => {
+                "FactorizeLeft"
+            },
+            HighLevelHibouRewriteRules::FactorizeRight => {
+                "FactorizeRight"
+            },
+            HighLevelHibouRewriteRules::DeFactorizeLeft => {
+                "DeFactorizeLeft"
+            },
+            HighLevelHibouRewriteRules::DeFactorizeRight => {
+                "DeFactorizeRight"
+            },
+            HighLevelHibouRewriteRules::KleeneDesequencing => {
+                "KleeneDesequencing"
+            },
+            HighLevelHibouRewriteRules::KleeneNesting => {
+                "KleeneNesting"
+            },
+            HighLevelHibouRewriteRules::KleeneTighteningModuloAC => {
+                "KleeneTighteningModuloAC"
+            },
+            HighLevelHibouRewriteRules::KleeneRolling => {
+                "KleeneRolling"
+            },
+            HighLevelHibouRewriteRules::StrictnessRelaxationBinary => {
+                "StrictnessRelaxationBinary"
+            },
+            HighLevelHibouRewriteRules::StrictnessRelaxationUnary => {
+                "StrictnessRelaxationUnary"
+            },
+            HighLevelHibouRewriteRules::SequencingCompatibilityLeft => {
+                "SequencingCompatibilityLeft"
+            },
+            HighLevelHibouRewriteRules::SequencingCompatibilityRight => {
+                "SequencingCompatibilityRight"
+            },
+            HighLevelHibouRewriteRules::BasicAltDeduplication => {
+                "BasicAltDeduplication"
+            },
+            HighLevelHibouRewriteRules::SummandInclusionModuloAC => {
+                "SummandInclusionModuloAC"
+            }        }
+    }
+
+    pub(crate) fn get_low_level_rewrite_rule(&self) -> Box<dyn RewriteRule<HibouRewritableLangOperator>> {
         match self {
             HighLevelHibouRewriteRules::StrictFlushRight => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::AssociativeFlushRight(Box::new(HibouAssocCheckerToFlushStrictRight{})),
-                    desc : "StrictFlushRight".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
             HighLevelHibouRewriteRules::AltAndCoregFlushRight => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::AssociativeFlushRight(Box::new(HibouAssocCheckerToFlushAltCoregRight{})),
-                    desc : "AltAndCoregFlushRight".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
             HighLevelHibouRewriteRules::ReorderSubInteractionsUnderAlt => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::ReorderOperandsIfCommuteModuloAC(Box::new(HibouCommutativeCheckerAndOrderer{consider_alt:true,consider_coreg:false})),
-                    desc : "ReorderSubInteractionsUnderAlt".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
             HighLevelHibouRewriteRules::ReorderSubInteractionsUnderCoreg => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::ReorderOperandsIfCommuteModuloAC(Box::new(HibouCommutativeCheckerAndOrderer{consider_alt:false,consider_coreg:true})),
-                    desc : "ReorderSubInteractionsUnderCoreg".to_owned()
+                    desc : self.get_desc().to_owned()
+                }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
+            },
+
+            HighLevelHibouRewriteRules::CoregionReorderAndMinimizeModuloAC => {
+                Box::new(BuiltinRewriteTransformation{
+                    kind : BuiltinRewriteTransformationKind::ModuloAssociativeGenericFlattenedTransfo(Box::new(HibouCoregionReordererAndMinimizer{})),
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
 
             HighLevelHibouRewriteRules::CoregionMinimizationBasic => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::GenericSimplifyUnderBinary(Box::new(HibouCoregionMinimizer{})),
-                    desc : "CoregionMinimizationBasic".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
             HighLevelHibouRewriteRules::CoregionMinimizationKleene => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::GenericSimplifyUnderUnary(Box::new(HibouCoregionMinimizer{})),
-                    desc : "CoregionMinimizationKleene".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
 
             HighLevelHibouRewriteRules::EpsilonFixpoint => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::GenericSimplifyUnderUnary(Box::new(HibouEmptyInteractionSimplifier{})),
-                    desc : "EpsilonFixpoint".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
             HighLevelHibouRewriteRules::EpsilonNeutral => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::GenericSimplifyUnderBinary(Box::new(HibouEmptyInteractionSimplifier{})),
-                    desc : "EpsilonNeutral".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
 
             HighLevelHibouRewriteRules::FactorizeLeft => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::FactorizeLeftDistributiveModuloAC(Box::new(HibouDistributivityChecker{})),
-                    desc : "FactorizeLeft".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
             HighLevelHibouRewriteRules::FactorizeRight => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::FactorizeRightDistributiveModuloAC(Box::new(HibouDistributivityChecker{})),
-                    desc : "FactorizeRight".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
             HighLevelHibouRewriteRules::DeFactorizeLeft => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::DeFactorizeLeftDistributive(Box::new(HibouDistributivityChecker{})),
-                    desc : "DeFactorizeLeft".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
             HighLevelHibouRewriteRules::DeFactorizeRight => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::DeFactorizeRightDistributive(Box::new(HibouDistributivityChecker{})),
-                    desc : "DeFactorizeRight".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
 
@@ -165,58 +276,64 @@ impl HighLevelHibouRewriteRules {
             HighLevelHibouRewriteRules::KleeneDesequencing => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::ModuloAssociativeGenericFlattenedTransfo(Box::new(HibouKleeneDesequencer{})),
-                    desc : "KleeneDesequencing".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
             HighLevelHibouRewriteRules::KleeneNesting => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::GenericSimplifyUnderUnary(Box::new(HibouKleeneNestingSimplifier{})),
-                    desc : "KleeneNesting".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
             HighLevelHibouRewriteRules::KleeneTighteningModuloAC => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::ModuloAssociativeGenericFlattenedTransfo(Box::new(HibouKleeneTightener{})),
-                    desc : "KleeneTighteningModuloAC".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
             HighLevelHibouRewriteRules::KleeneRolling => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::GenericSimplifyUnderBinary(Box::new(HibouKleeneRoller{})),
-                    desc : "KleeneRolling".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
 
             HighLevelHibouRewriteRules::StrictnessRelaxationBinary => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::GenericSimplifyUnderBinary(Box::new(HibouStrictnessRelaxer{})),
-                    desc : "StrictnessRelaxationBinary".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
             HighLevelHibouRewriteRules::StrictnessRelaxationUnary => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::GenericSimplifyUnderUnary(Box::new(HibouStrictnessRelaxer{})),
-                    desc : "StrictnessRelaxationUnary".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
 
             HighLevelHibouRewriteRules::SequencingCompatibilityLeft => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::GenericSimplifyUnderBinary(Box::new(HibouLeftSequencingCompatibilizer{})),
-                    desc : "SequencingCompatibilityLeft".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
             HighLevelHibouRewriteRules::SequencingCompatibilityRight => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::GenericSimplifyUnderBinary(Box::new(HibouRightSequencingCompatibilizer{})),
-                    desc : "SequencingCompatibilityRight".to_owned()
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
 
             HighLevelHibouRewriteRules::BasicAltDeduplication => {
                 Box::new(BuiltinRewriteTransformation{
                     kind : BuiltinRewriteTransformationKind::ModuloAssociativeGenericFlattenedTransfo(Box::new(HibouAltDeduplicator{})),
-                    desc : "BasicAltDeduplication".to_owned()
+                    desc : self.get_desc().to_owned()
+                }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
+            },
+            HighLevelHibouRewriteRules::SummandInclusionModuloAC => {
+                Box::new(BuiltinRewriteTransformation{
+                    kind : BuiltinRewriteTransformationKind::ModuloAssociativeGenericFlattenedTransfo(Box::new(HibouAltSummandIncluder{})),
+                    desc : self.get_desc().to_owned()
                 }) as Box<dyn RewriteRule<HibouRewritableLangOperator>>
             },
         }
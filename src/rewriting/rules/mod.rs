@@ -16,4 +16,4 @@ limitations under the License.
 
 pub mod high_level_hibou_rewrite_rules;
 
-pub mod transfos_canonize; 
\ No newline at end of file
+pub(in crate::rewriting) mod transfos_canonize; 
\ No newline at end of file
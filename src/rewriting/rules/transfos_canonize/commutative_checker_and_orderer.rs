@@ -118,7 +118,7 @@ impl ModuloAssociativePartialReorderer<HibouRewritableLangOperator> for HibouCom
 
 
 
-fn compare_hibou_lang_operators(op1: &HibouRewritableLangOperator, op2: &HibouRewritableLangOperator) -> Ordering {
+pub(in crate::rewriting) fn compare_hibou_lang_operators(op1: &HibouRewritableLangOperator, op2: &HibouRewritableLangOperator) -> Ordering {
     match (op1,op2) {
         (HibouRewritableLangOperator::Empty,HibouRewritableLangOperator::Empty) => {
             Ordering::Equal
@@ -209,15 +209,15 @@ fn compare_hibou_lang_operators(op1: &HibouRewritableLangOperator, op2: &HibouRe
         (_,HibouRewritableLangOperator::Loop(_)) => {
             Ordering::Greater
         },
-        /*(Interaction::Sync(self_acts,self_i1,self_i2),Interaction::Sync(other_acts,other_i1,other_i2)) => {
-            let max_acts_len = self_acts.len().max(other_acts.len());
+        (HibouRewritableLangOperator::Sync(acts1),HibouRewritableLangOperator::Sync(acts2)) => {
+            let max_acts_len = acts1.len().max(acts2.len());
             for i in 0..max_acts_len {
-                match (self_acts.get(i) ,other_acts.get(i) ) {
-                    ( Some( cr_ref1 ), Some(cr_ref2) ) => {
-                        if cr_ref1 < cr_ref2 {
+                match (acts1.get(i) ,acts2.get(i) ) {
+                    ( Some( act_ref1 ), Some(act_ref2) ) => {
+                        if act_ref1 < act_ref2 {
                             return Ordering::Less;
                         }
-                        if cr_ref1 > cr_ref2 {
+                        if act_ref1 > act_ref2 {
                             return Ordering::Greater;
                         }
                     },
@@ -230,23 +230,14 @@ fn compare_hibou_lang_operators(op1: &HibouRewritableLangOperator, op2: &HibouRe
                     (None,None) => {}
                 }
             }
-            // ***
-            let cmp_left = self_i1.cmp(other_i1);
-            match &cmp_left {
-                Ordering::Equal => {
-                    return self_i2.cmp(other_i2);
-                },
-                _ => {
-                    return cmp_left;
-                }
-            }
+            Ordering::Equal
+        },
+        (HibouRewritableLangOperator::Sync(_),_) => {
+            Ordering::Less
         },
-        (Interaction::Sync(_,_,_),_) => {
-            return Ordering::Less;
+        (_,HibouRewritableLangOperator::Sync(_)) => {
+            Ordering::Greater
         },
-        (_,Interaction::Sync(_,_,_)) => {
-            return Ordering::Greater;
-        },*/
         (HibouRewritableLangOperator::And,HibouRewritableLangOperator::And) => {
             Ordering::Equal
         }
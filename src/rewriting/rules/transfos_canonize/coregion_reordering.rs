@@ -0,0 +1,188 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use simple_term_rewriter::builtin_trs::rules::modulo_associative_flattened_transfo::ModuloAssociativeGenericFlattenedChecker;
+use simple_term_rewriter::builtin_trs::util::{fold_associative_sub_terms_recursively, lexicographic_path_ordering};
+use simple_term_rewriter::core::terms::conversion::from_rewritable_term::FromRewritableTermToDomainSpecificTerm;
+use simple_term_rewriter::core::terms::term::LanguageTerm;
+
+use crate::core::syntax::interaction::Interaction;
+use crate::core::syntax::lang_traits::involve::involves::InvolvesLifelines;
+use crate::rewriting::lang::HibouRewritableLangOperator;
+
+use super::commutative_checker_and_orderer::{compare_hibou_lang_operators, may_commute_under_coreg};
+
+
+
+/**
+ * Under a flattened coreg(cr,i1,...,in):
+ * - nested coregions which do not allow more or less interleavings are flattened as well
+ * - the lifelines of cr which are not involved in at least two of the i1,...,in are removed
+ * - the i1,...,in are reordered so as to get the least order (as per the lexicographic path ordering)
+ *   that respects the dependencies between the sub-interactions that may not commute
+ * 
+ * The resulting coregions all have the same lifelines so that the result can be flattened again.
+ * This is why, in the "full_canonical_with_coregion_reordering" preset, this rule replaces
+ * both "CoregionMinimizationBasic" and "ReorderSubInteractionsUnderCoreg",
+ * which minimize the coregions of each binary operator separately.
+ **/
+pub struct HibouCoregionReordererAndMinimizer {}
+
+
+impl ModuloAssociativeGenericFlattenedChecker<HibouRewritableLangOperator> for HibouCoregionReordererAndMinimizer {
+    fn is_an_associative_binary_operator_we_may_consider(
+        &self, 
+        op : &HibouRewritableLangOperator
+    ) -> bool {
+        matches!(op, HibouRewritableLangOperator::CoReg(_))
+    }
+
+    fn requires_a_specific_parent_operator(
+        &self,
+    ) -> Option<Box<dyn Fn(&HibouRewritableLangOperator) -> bool>> {
+        None
+    }
+
+    fn transform_flattened_sub_terms(
+        &self, 
+        considered_ac_op : &HibouRewritableLangOperator, 
+        _considered_parent_op : Option<&HibouRewritableLangOperator>,
+        flattened_subterms : Vec<&LanguageTerm<HibouRewritableLangOperator>>
+    ) -> Option<Vec<LanguageTerm<HibouRewritableLangOperator>>> {
+        // example transformation:
+        // seq(l1 -- m3 -> |, l1 -- m1 -> |, l3 -- m2 -> |) -> seq(l3 -- m2 -> |, l1 -- m3 -> |, l1 -- m1 -> |)
+        let cr = match considered_ac_op {
+            HibouRewritableLangOperator::CoReg(cr) => {
+                cr
+            },
+            _ => {
+                panic!("should not be reached")
+            }
+        };
+        // ***
+        let mut sub_terms = vec![];
+        for sub_term in &flattened_subterms {
+            flatten_sub_term_under_coreg(cr, sub_term, &mut sub_terms);
+        }
+        let involved : Vec<BTreeSet<usize>> = sub_terms.iter()
+            .map(|sub_term| Interaction::from_rewritable_term(sub_term).lifelines_that_may_be_involved())
+            .collect();
+        // ***
+        let new_cr : Vec<usize> = cr.iter().filter(
+            |lf| involved.iter().filter(|inv| inv.contains(lf)).count() >= 2
+        ).cloned().collect();
+        // ***
+        let mut reordered = reorder_sub_terms_under_coreg(&new_cr, sub_terms);
+        let new_term = fold_associative_sub_terms_recursively(
+            &HibouRewritableLangOperator::CoReg(new_cr), 
+            &mut reordered, 
+            &None
+        );
+        let old_term = fold_associative_sub_terms_recursively(
+            considered_ac_op, 
+            &mut flattened_subterms.into_iter().cloned().collect(), 
+            &None
+        );
+        if new_term != old_term {
+            // the operator may have changed so we return the already folded term
+            Some(vec![new_term])
+        } else {
+            None
+        }
+    }
+}
+
+
+/** 
+ * coreg(cr1,i1,i2) may be flattened under coreg(cr,...) if it is equivalent to coreg(cr,i1,i2)
+ * i.e. if the lifelines that are in either cr or cr1 but not in both are not involved in both i1 and i2.
+ * **/
+fn flatten_sub_term_under_coreg(
+    cr : &[usize],
+    sub_term : &LanguageTerm<HibouRewritableLangOperator>,
+    flattened : &mut Vec<LanguageTerm<HibouRewritableLangOperator>>
+) {
+    if let HibouRewritableLangOperator::CoReg(sub_cr) = &sub_term.operator {
+        let left = sub_term.sub_terms.first().unwrap();
+        let right = sub_term.sub_terms.get(1).unwrap();
+        let involved_in_both : BTreeSet<usize> = {
+            let left_involved = Interaction::from_rewritable_term(left).lifelines_that_may_be_involved();
+            let right_involved = Interaction::from_rewritable_term(right).lifelines_that_may_be_involved();
+            left_involved.intersection(&right_involved).cloned().collect()
+        };
+        let is_equivalent_under_cr = involved_in_both.iter().all(
+            |lf| cr.contains(lf) == sub_cr.contains(lf)
+        );
+        if is_equivalent_under_cr {
+            flatten_sub_term_under_coreg(cr, left, flattened);
+            flatten_sub_term_under_coreg(cr, right, flattened);
+            return;
+        }
+    }
+    flattened.push(sub_term.clone());
+}
+
+
+/** 
+ * Topological sort of the sub-interactions as per their dependencies
+ * in which, at each step, the least sub-interaction is picked among those which dependencies are already placed.
+ * **/
+fn reorder_sub_terms_under_coreg(
+    cr : &Vec<usize>,
+    sub_terms : Vec<LanguageTerm<HibouRewritableLangOperator>>
+) -> Vec<LanguageTerm<HibouRewritableLangOperator>> {
+    let sub_terms_num = sub_terms.len();
+    // "predecessors[j]" contains the indices i<j of the sub-interactions which must occur before the j-th one
+    let predecessors : Vec<Vec<usize>> = (0..sub_terms_num).map(
+        |j| (0..j).filter(
+            |i| !may_commute_under_coreg(cr, sub_terms.get(*i).unwrap(), sub_terms.get(j).unwrap())
+        ).collect()
+    ).collect();
+    // ***
+    let mut is_placed = vec![false;sub_terms_num];
+    let mut order = vec![];
+    while order.len() < sub_terms_num {
+        let mut least : Option<usize> = None;
+        for j in 0..sub_terms_num {
+            if is_placed[j] || !predecessors[j].iter().all(|i| is_placed[*i]) {
+                continue;
+            }
+            least = match least {
+                None => {
+                    Some(j)
+                },
+                Some(k) => {
+                    let ordering = lexicographic_path_ordering(
+                        sub_terms.get(j).unwrap(), 
+                        sub_terms.get(k).unwrap(), 
+                        &compare_hibou_lang_operators, 
+                        &|op| op.arity()
+                    );
+                    if ordering.is_lt() { Some(j) } else { Some(k) }
+                }
+            };
+        }
+        let least = least.unwrap();
+        is_placed[least] = true;
+        order.push(least);
+    }
+    // ***
+    let mut sub_terms : Vec<Option<LanguageTerm<HibouRewritableLangOperator>>> = sub_terms.into_iter().map(Some).collect();
+    order.into_iter().map(|idx| sub_terms.get_mut(idx).unwrap().take().unwrap()).collect()
+}
@@ -69,7 +69,7 @@ impl ModuloAssociativeGenericFlattenedChecker<HibouRewritableLangOperator> for H
                     new_flattened_sub_terms.push((*sub_int).clone());
                 }
             }
-            new_flattened_sub_terms.insert(index_to_add - 1, new_loop);
+            new_flattened_sub_terms.insert(index_to_add, new_loop);
             return Some(new_flattened_sub_terms);
         }
 
@@ -191,7 +191,7 @@ fn merge_loops(
 ) -> Option<(LoopKind,LanguageTerm<HibouRewritableLangOperator>)> {
     let l1_as_int = Interaction::from_rewritable_term(loop1_content);
     let l2_as_int = Interaction::from_rewritable_term(loop2_content);
-    if loop1_lk.is_more_permissive(loop2_lk).unwrap() && 
+    if loop1_lk.is_more_permissive(loop2_lk) == Some(true) && 
     check_inclusion_of_interactions(&l2_as_int,&l1_as_int,None) == InteractionInclusionGlobalVerdict::IsIncluded {
         // we can retain the first loop and eliminate the second
         return Some(
@@ -201,7 +201,7 @@ fn merge_loops(
             )
         );
     }
-    if loop2_lk.is_more_permissive(loop1_lk).unwrap() && 
+    if loop2_lk.is_more_permissive(loop1_lk) == Some(true) && 
     check_inclusion_of_interactions(&l1_as_int,&l2_as_int,None) == InteractionInclusionGlobalVerdict::IsIncluded {
         // we can retain the second loop and eliminate the first
         return Some(
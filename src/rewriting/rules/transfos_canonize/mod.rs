@@ -22,6 +22,7 @@ limitations under the License.
 pub mod basic_alt_deduplication;
 pub mod commutative_checker_and_orderer;
 pub mod coregion_minimization;
+pub mod coregion_reordering;
 pub mod distributivity_checker;
 pub mod empty_interaction_simplifier;
 pub mod kleene_desequencing;
@@ -31,5 +32,5 @@ pub mod kleene_tightening;
 pub mod sequencing_compatibility;
 pub mod flush_right;
 pub mod strictness_relaxation;  
-//pub mod summand_inclusion;  
+pub mod summand_inclusion;
 
@@ -78,14 +78,10 @@ impl GenericBinaryOperatorSimplifier<HibouRewritableLangOperator> for HibouLeftS
             for idx in 0..unfolded_on_the_left.len() {
                 let ik = unfolded_on_the_left.get(idx).unwrap();
                 let empty_cr = vec![];
-                let may_commute = if idx > 0 {
-                    (0..(idx-1)).all(|earlier_idx| {
-                        let earlier_i = unfolded_on_the_left.get(earlier_idx).unwrap();
-                        may_commute_under_coreg(&empty_cr, earlier_i, ik)
-                    })
-                } else {
-                    true
-                };
+                let may_commute = (0..idx).all(|earlier_idx| {
+                    let earlier_i = unfolded_on_the_left.get(earlier_idx).unwrap();
+                    may_commute_under_coreg(&empty_cr, earlier_i, ik)
+                });
                 if may_commute {
                     let last_locations_on_ik : HashSet<usize> = {
                         let ik_as_int = Interaction::from_rewritable_term(
@@ -14,15 +14,29 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-use simple_term_rewriter::{builtin_trs::rules::modulo_associative_flattened_transfo::ModuloAssociativeFlattenedChecker, core::term::LanguageTerm};
 
+use simple_term_rewriter::builtin_trs::rules::modulo_associative_flattened_transfo::ModuloAssociativeGenericFlattenedChecker;
+use simple_term_rewriter::core::terms::term::LanguageTerm;
+use simple_term_rewriter::core::terms::conversion::from_rewritable_term::FromRewritableTermToDomainSpecificTerm;
+
+use crate::core::syntax::interaction::Interaction;
+use crate::inclusion_checker::check_inclusion::{check_inclusion_of_interactions_with_options, InteractionInclusionGlobalVerdict};
+use crate::inclusion_checker::options::InteractionInclusionCheckingOptions;
 use crate::rewriting::lang::HibouRewritableLangOperator;
 
 
+
+/**
+ * The number of nodes beyond which we give up on establishing the inclusion of a summand in another.
+ * In that case the summand is kept.
+ **/
+const SUMMAND_INCLUSION_MAX_NODE_NUMBER : u32 = 500;
+
+
 pub struct HibouAltSummandIncluder {}
 
 
-impl ModuloAssociativeFlattenedChecker<HibouRewritableLangOperator> for HibouAltSummandIncluder {
+impl ModuloAssociativeGenericFlattenedChecker<HibouRewritableLangOperator> for HibouAltSummandIncluder {
     fn is_an_associative_binary_operator_we_may_consider(
         &self, 
         op : &HibouRewritableLangOperator
@@ -30,10 +44,9 @@ impl ModuloAssociativeFlattenedChecker<HibouRewritableLangOperator> for HibouAlt
         op == &HibouRewritableLangOperator::Alt
     }
 
-    fn if_required_is_a_parent_unary_operator_we_may_consider(
+    fn requires_a_specific_parent_operator(
         &self,
-        _op : &HibouRewritableLangOperator
-    ) -> Option<bool> {
+    ) -> Option<Box<dyn Fn(&HibouRewritableLangOperator) -> bool>> {
         None
     }
 
@@ -41,15 +54,52 @@ impl ModuloAssociativeFlattenedChecker<HibouRewritableLangOperator> for HibouAlt
         &self, 
         _considered_ac_op : &HibouRewritableLangOperator, 
         _considered_parent_op : Option<&HibouRewritableLangOperator>,
-        _flattened_subterms : Vec<&LanguageTerm<HibouRewritableLangOperator>>
-    ) -> Option<(Option<HibouRewritableLangOperator>,Vec<LanguageTerm<HibouRewritableLangOperator>>)> {
-        todo!()
+        flattened_subterms : Vec<&LanguageTerm<HibouRewritableLangOperator>>
+    ) -> Option<Vec<LanguageTerm<HibouRewritableLangOperator>>> {
+        // example transformation:
+        // alt(i1,i2,loopS(i2)) -> alt(i1,loopS(i2))
+        let mut kept : Vec<(&LanguageTerm<HibouRewritableLangOperator>,Interaction)> = flattened_subterms.iter()
+            .map(|sub_term| (*sub_term,Interaction::from_rewritable_term(sub_term)))
+            .collect();
+        let mut sub_int_idx = 0;
+        while sub_int_idx < kept.len() {
+            let (_,sub_int) = kept.get(sub_int_idx).unwrap();
+            // comparing only with the summands that are kept
+            // ensures that only one of two summands with the same language is removed
+            let is_included_in_another = kept.iter().enumerate().any(
+                |(other_idx,(_,other_int))| other_idx != sub_int_idx && is_included_within_budget(sub_int, other_int)
+            );
+            if is_included_in_another {
+                kept.remove(sub_int_idx);
+            } else {
+                sub_int_idx += 1;
+            }
+        }
+        if kept.len() < flattened_subterms.len() {
+            Some(kept.into_iter().map(|(sub_term,_)| sub_term.clone()).collect())
+        } else {
+            None
+        }
     }
 }
 
 
-
-
-
-
-
+/** 
+ * Only a definite inclusion verdict is trusted.
+ * If the budget is exceeded, the inclusion is considered not to hold.
+ * **/
+fn is_included_within_budget(
+    included_candidate : &Interaction,
+    including_candidate : &Interaction
+) -> bool {
+    let options = InteractionInclusionCheckingOptions {
+        max_node_number : Some(SUMMAND_INCLUSION_MAX_NODE_NUMBER),
+        ..Default::default()
+    };
+    check_inclusion_of_interactions_with_options(
+        included_candidate, 
+        including_candidate, 
+        &options, 
+        None
+    ) == InteractionInclusionGlobalVerdict::IsIncluded
+}
@@ -0,0 +1,299 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use simple_term_rewriter::core::terms::conversion::from_rewritable_term::FromRewritableTermToDomainSpecificTerm;
+use simple_term_rewriter::core::terms::conversion::to_rewritable_term::FromDomainSpecificTermToRewritableTerm;
+use simple_term_rewriter::rewriting_process::apply::get_transformations;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::semantics::accepted_traces::accepted_traces;
+use crate::core::semantics::trace_action::TraceAction;
+use crate::core::syntax::interaction::Interaction;
+#[cfg(feature = "generation")]
+use crate::generation::conf::InteractionGenerationConfig;
+#[cfg(feature = "generation")]
+use crate::generation::generate::generate_interaction_from_seed;
+use crate::seqdiag_lib_interface::io::{interaction_as_text, trace_as_text};
+
+use super::derivation::RewriteRuleApplication;
+use super::rules::high_level_hibou_rewrite_rules::HighLevelHibouRewriteRules;
+
+
+
+/**
+ * A single application of a rewrite rule which changes the trace language of the interaction.
+ * The distinguishing trace is the smallest trace (in length then in lexicographic order)
+ * accepted by only one of the original and rewritten interactions.
+ **/
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct RewriteSoundnessViolation {
+    pub original : Interaction,
+    pub application : RewriteRuleApplication,
+    pub rewritten : Interaction,
+    pub distinguishing_trace : Vec<TraceAction>,
+    // whether the distinguishing trace is accepted by the original interaction (and not by the rewritten one) or the converse
+    pub accepted_by_original : bool
+}
+
+impl RewriteSoundnessViolation {
+
+    pub fn as_text(&self, ctx : &GeneralContext) -> String {
+        let (accepting,rejecting) = if self.accepted_by_original {
+            ("original","rewritten")
+        } else {
+            ("rewritten","original")
+        };
+        format!(
+            "{} at position {:?}\noriginal:\n{}\nrewritten:\n{}\ntrace {} is accepted by the {} interaction but not by the {} one\n",
+            self.application.rule.get_desc(),
+            self.application.position,
+            interaction_as_text(ctx, &self.original, true),
+            interaction_as_text(ctx, &self.rewritten, true),
+            trace_as_text(ctx, &self.distinguishing_trace),
+            accepting,
+            rejecting
+        )
+    }
+
+}
+
+
+
+/**
+ * All the traces of length at most "max_trace_length" which are accepted by the interaction.
+ **/
+pub fn bounded_trace_language(int : &Interaction, max_trace_length : u32) -> BTreeSet<Vec<TraceAction>> {
+    accepted_traces(int, max_trace_length, None).collect()
+}
+
+
+
+/**
+ * All the interactions obtained by applying the rule exactly once, at any position.
+ **/
+pub fn apply_rule_once(
+    rule : HighLevelHibouRewriteRules,
+    int : &Interaction
+) -> Vec<(RewriteRuleApplication,Interaction)> {
+    let rules = vec![rule.get_low_level_rewrite_rule()];
+    get_transformations(0, &rules, &int.to_rewritable_term(), false).into_iter().map(
+        |mut transfo| {
+            let application = RewriteRuleApplication::new(
+                rule,
+                transfo.position.get_absolute_coordinates_from_root().to_vec()
+            );
+            (application,Interaction::from_rewritable_term(&transfo.result.take().unwrap()))
+        }
+    ).collect()
+}
+
+
+
+/**
+ * Applies the rule once at every position where it applies and compares the bounded trace languages
+ * of the interaction and of each rewritten interaction.
+ * Returns the first application which changes the bounded trace language, if any.
+ **/
+pub fn find_soundness_violation(
+    rule : HighLevelHibouRewriteRules,
+    int : &Interaction,
+    max_trace_length : u32
+) -> Option<RewriteSoundnessViolation> {
+    let applications = apply_rule_once(rule, int);
+    if applications.is_empty() {
+        return None;
+    }
+    let original_language = bounded_trace_language(int, max_trace_length);
+    for (application,rewritten) in applications {
+        let rewritten_language = bounded_trace_language(&rewritten, max_trace_length);
+        let smallest_difference = original_language.symmetric_difference(&rewritten_language).min_by(
+            |t1,t2| t1.len().cmp(&t2.len()).then_with(|| t1.cmp(t2))
+        );
+        if let Some(trace) = smallest_difference {
+            return Some(
+                RewriteSoundnessViolation {
+                    original : int.clone(),
+                    application,
+                    rewritten,
+                    distinguishing_trace : trace.clone(),
+                    accepted_by_original : original_language.contains(trace)
+                }
+            );
+        }
+    }
+    None
+}
+
+
+
+/**
+ * Greedily shrinks the original interaction of the violation for as long as
+ * applying the same rule on the shrunk interaction still changes its bounded trace language.
+ **/
+pub fn minimize_soundness_violation(
+    violation : RewriteSoundnessViolation,
+    max_trace_length : u32
+) -> RewriteSoundnessViolation {
+    let rule = violation.application.rule;
+    shrink_counterexample(
+        violation,
+        |found| &found.original,
+        |candidate| find_soundness_violation(rule, candidate, max_trace_length)
+    )
+}
+
+/**
+ * Greedily replaces the counterexample with a counterexample found on a smaller interaction
+ * until none of the smaller interactions yields one.
+ * A sub-interaction is shrunk by replacing it either with the empty interaction or with one of its operands.
+ **/
+pub(crate) fn shrink_counterexample<T>(
+    counterexample : T,
+    get_interaction : impl Fn(&T) -> &Interaction,
+    find_counterexample : impl Fn(&Interaction) -> Option<T>
+) -> T {
+    let mut current = counterexample;
+    'shrinking : loop {
+        for candidate in get_shrinking_candidates(get_interaction(&current)) {
+            if let Some(smaller) = find_counterexample(&candidate) {
+                current = smaller;
+                continue 'shrinking;
+            }
+        }
+        return current;
+    }
+}
+
+// strictly smaller interactions, the smallest ones first
+fn get_shrinking_candidates(int : &Interaction) -> Vec<Interaction> {
+    let mut candidates = vec![];
+    if *int != Interaction::Empty {
+        candidates.push(Interaction::Empty);
+    }
+    match int {
+        Interaction::Empty | Interaction::Emission(_) | Interaction::Reception(_) => {
+            // nothing else
+        },
+        Interaction::Loop(lk,i1) => {
+            candidates.push(*i1.clone());
+            for sub_candidate in get_shrinking_candidates(i1) {
+                candidates.push(Interaction::Loop(lk.clone(),Box::new(sub_candidate)));
+            }
+        },
+        Interaction::Strict(i1,i2) |
+        Interaction::CoReg(_,i1,i2) |
+        Interaction::Alt(i1,i2) |
+        Interaction::And(i1,i2) |
+        Interaction::Sync(_,i1,i2) => {
+            candidates.push(*i1.clone());
+            candidates.push(*i2.clone());
+            for sub_candidate in get_shrinking_candidates(i1) {
+                candidates.push(with_operands(int,sub_candidate,*i2.clone()));
+            }
+            for sub_candidate in get_shrinking_candidates(i2) {
+                candidates.push(with_operands(int,*i1.clone(),sub_candidate));
+            }
+        }
+    }
+    candidates
+}
+
+// the same binary operator applied to other operands
+fn with_operands(int : &Interaction, i1 : Interaction, i2 : Interaction) -> Interaction {
+    let (i1,i2) = (Box::new(i1),Box::new(i2));
+    match int {
+        Interaction::Strict(_,_) => {
+            Interaction::Strict(i1,i2)
+        },
+        Interaction::CoReg(cr,_,_) => {
+            Interaction::CoReg(cr.clone(),i1,i2)
+        },
+        Interaction::Alt(_,_) => {
+            Interaction::Alt(i1,i2)
+        },
+        Interaction::And(_,_) => {
+            Interaction::And(i1,i2)
+        },
+        Interaction::Sync(sync_acts,_,_) => {
+            Interaction::Sync(sync_acts.clone(),i1,i2)
+        },
+        _ => {
+            panic!("not a binary operator : {:?}", int);
+        }
+    }
+}
+
+
+
+/**
+ * Checks that rewrite rules preserve the trace language on randomly generated interactions.
+ * Trace languages are compared up to traces of length "max_trace_length".
+ * The interactions are generated from the seeds "seed", "seed+1", ..., "seed+samples_num-1".
+ **/
+#[cfg(feature = "generation")]
+#[derive(Clone, PartialEq, Debug)]
+pub struct RewriteSoundnessHarness {
+    pub max_trace_length : u32,
+    pub samples_num : u64,
+    pub seed : u64,
+    pub generation_config : InteractionGenerationConfig
+}
+
+#[cfg(feature = "generation")]
+impl RewriteSoundnessHarness {
+
+    pub fn new(
+        max_trace_length : u32,
+        samples_num : u64,
+        seed : u64,
+        generation_config : InteractionGenerationConfig
+    ) -> Self {
+        Self { max_trace_length, samples_num, seed, generation_config }
+    }
+
+    /**
+     * Returns a minimized counterexample for the first generated interaction
+     * on which applying the rule changes the bounded trace language, if any.
+     **/
+    pub fn check_rule(
+        &self,
+        ctx : &GeneralContext,
+        rule : HighLevelHibouRewriteRules
+    ) -> Option<RewriteSoundnessViolation> {
+        for sample_id in 0..self.samples_num {
+            let int = generate_interaction_from_seed(ctx, &self.generation_config, self.seed.wrapping_add(sample_id));
+            if let Some(violation) = find_soundness_violation(rule, &int, self.max_trace_length) {
+                return Some(minimize_soundness_violation(violation, self.max_trace_length));
+            }
+        }
+        None
+    }
+
+    /**
+     * Checks each rule individually and returns a counterexample for each unsound rule.
+     **/
+    pub fn check_rules(
+        &self,
+        ctx : &GeneralContext,
+        rules : &[HighLevelHibouRewriteRules]
+    ) -> Vec<RewriteSoundnessViolation> {
+        rules.iter().filter_map(|rule| self.check_rule(ctx, *rule)).collect()
+    }
+
+}
@@ -49,6 +49,9 @@ impl FromInteractionTermToInternalRepresentation<HibouLangCioII> for Interaction
             Interaction::And(i1, i2) => {
                 vec![&*i1,&*i2]
             }
+            Interaction::Sync(_,i1, i2) => {
+                vec![&*i1,&*i2]
+            }
             Interaction::Empty => {
                 vec![]
             },
@@ -78,6 +81,9 @@ impl FromInteractionTermToInternalRepresentation<HibouLangCioII> for Interaction
             Interaction::And(_,_) => {
                 Some(HibouOperators::And)
             },
+            Interaction::Sync(sync_acts,_,_) => {
+                Some(HibouOperators::Sync(sync_acts.clone()))
+            },
             _ => {
                 None 
             }
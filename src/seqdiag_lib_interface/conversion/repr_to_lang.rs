@@ -47,6 +47,9 @@ impl FromInternalRepresentationToInteractionTerm<HibouLangCioII> for Interaction
             HibouOperators::And => {
                 Some(Interaction::And(Box::new(i1), Box::new(i2)))
             }
+            HibouOperators::Sync(sync_acts) => {
+                Some(Interaction::Sync(sync_acts.clone(), Box::new(i1), Box::new(i2)))
+            }
             HibouOperators::Loop(_) => {
                 panic!()
             }
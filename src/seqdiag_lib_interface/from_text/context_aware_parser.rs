@@ -18,27 +18,144 @@ limitations under the License.
 
 use nom::branch::alt;
 use nom::bytes::complete::tag;
+use nom::character::complete::{char, multispace0};
 use nom::combinator::{map, value};
-use nom::error::ParseError;
+use nom::error::{ErrorKind, ParseError};
+use nom::sequence::{delimited, preceded, tuple};
 use nom::IResult;
 
 
 use common_sequence_diagram_io::from_text::context_aware_parser::ContextAwareInteractionParser;
+use common_sequence_diagram_io::internal_representation::InteractionInternalRepresentation;
 
 
 
 use common_sequence_diagram_io::from_text::util::delimited_lang_parser::DelimitedInteractionLanguageParser;
 use common_sequence_diagram_io::from_text::util::generic_broadcast_parser::GenericBroadcastParser;
 use common_sequence_diagram_io::from_text::util::parse_utils::parse_element_of_preexisting_vec_and_return_index;
-use nom::character::complete::multispace0;
-use nom::multi::separated_list0;
-use nom::sequence::tuple;
 
+use crate::core::semantics::trace_action::{TraceAction, TraceActionKind};
 use crate::core::syntax::interaction::*;
 use crate::core::general_context::GeneralContext;
+use crate::seqdiag_lib_interface::from_text::parsing_error::InteractionParsingFailure;
 use crate::seqdiag_lib_interface::internal_representation::{HibouBroadcastLeafPattern, HibouBroadcastOrigin, HibouLangCioII, HibouLeafPattern, HibouOperators};
 
 
+
+const OPERATOR_KEYWORDS : [&str;12] = [
+    "strict", "seq", "par", "coreg", "alt", "and",
+    "loopS", "loopH", "loopW", "loopP", "loopC", "sync"
+];
+
+type InteractionParsingResult<'a,O> = IResult<&'a str, O, InteractionParsingFailure>;
+
+/**
+ * The grammar of interactions is implemented once, with parsers that return an *InteractionParsingFailure*.
+ * The parsers that are generic in the error type, as required by the traits of *common_sequence_diagram_io*,
+ * delegate to them and only retain the location of the failure.
+ **/
+fn into_generic_result<'a, O, E: ParseError<&'a str>>(
+    input : &'a str,
+    result : InteractionParsingResult<'a,O>
+) -> IResult<&'a str, O, E> {
+    result.map_err(
+        |e| e.map(|failure| E::from_error_kind(&input[input.len() - failure.remaining_len..], ErrorKind::Fail))
+    )
+}
+
+fn parse_symbol<'a>(symbol : &'static str, input : &'a str) -> InteractionParsingResult<'a,&'a str> {
+    tag::<_,_,InteractionParsingFailure>(symbol)(input).map_err(
+        |e| e.map(|_| InteractionParsingFailure::expecting(input, &format!("'{}'", symbol), &[]))
+    )
+}
+
+fn parse_name<'a>(
+    names : &[String],
+    description : &str,
+    input : &'a str
+) -> InteractionParsingResult<'a,usize> {
+    parse_element_of_preexisting_vec_and_return_index::<InteractionParsingFailure>(names, input).map_err(
+        |e| e.map(|_| InteractionParsingFailure::expecting(input, description, names))
+    )
+}
+
+/**
+ * Parses a possibly empty list of elements separated by *separator*.
+ * Alongside the elements, returns the failure which ended the list
+ * so that it can be reported if what follows the list cannot be parsed either.
+ **/
+fn parse_separated_list<'a, O, S, P>(
+    mut separator : S,
+    mut element : P,
+    input : &'a str
+) -> InteractionParsingResult<'a,(Vec<O>,InteractionParsingFailure)> 
+    where
+        S : FnMut(&'a str) -> InteractionParsingResult<'a,&'a str>,
+        P : FnMut(&'a str) -> InteractionParsingResult<'a,O> {
+    let mut elements = vec![];
+    let mut remaining = input;
+    loop {
+        let after_separator = if elements.is_empty() {
+            remaining
+        } else {
+            match separator(remaining) {
+                Ok((rem,_)) => {
+                    rem
+                },
+                Err(nom::Err::Error(failure)) => {
+                    return Ok((remaining,(elements,failure)));
+                },
+                Err(e) => {
+                    return Err(e);
+                }
+            }
+        };
+        match element(after_separator) {
+            Ok((rem,got)) => {
+                elements.push(got);
+                remaining = rem;
+            },
+            Err(nom::Err::Error(failure)) => {
+                return Ok((remaining,(elements,failure)));
+            },
+            Err(e) => {
+                return Err(e);
+            }
+        }
+    }
+}
+
+// a list between parenthesis e.g. *(l1,l2)*
+fn parse_parenthesized_list<'a, O, P>(
+    element : P,
+    non_empty : bool,
+    input : &'a str
+) -> InteractionParsingResult<'a,Vec<O>> 
+    where
+        P : FnMut(&'a str) -> InteractionParsingResult<'a,O> {
+    let (rem,_) = tuple((char('('), multispace0))(input)?;
+    let (rem,(elements,list_failure)) = parse_separated_list(
+        delimited(multispace0, |x| parse_symbol(",",x), multispace0),
+        element,
+        rem
+    )?;
+    if non_empty && elements.is_empty() {
+        return Err(nom::Err::Error(list_failure));
+    }
+    match preceded(multispace0, char::<_,InteractionParsingFailure>(')'))(rem) {
+        Ok((rem,_)) => {
+            Ok((rem,elements))
+        },
+        Err(nom::Err::Error(failure)) => {
+            Err(nom::Err::Error(list_failure.or(failure)))
+        },
+        Err(e) => {
+            Err(e)
+        }
+    }
+}
+
+
 impl DelimitedInteractionLanguageParser for GeneralContext {
 
     fn left_parenthesis_char(&self) -> char {
@@ -97,129 +214,229 @@ impl GenericBroadcastParser<HibouBroadcastOrigin,usize,HibouBroadcastOrigin,Hibo
     }
 
     fn parse_message<'a, E: ParseError<&'a str>>(&self, input : &'a str) -> IResult<&'a str, usize,E> {
-        parse_element_of_preexisting_vec_and_return_index(self.get_ms_names(),input)
+        into_generic_result(input, self.parse_message_with_failure(input))
     }
 
     fn parse_broadcast_origin<'a, E: ParseError<&'a str>>(&self, input : &'a str) -> IResult<&'a str, HibouBroadcastOrigin,E> {
+        into_generic_result(input, self.parse_broadcast_origin_with_failure(input))
+    }
+
+    fn parse_single_broadcast_targets<'a, E: ParseError<&'a str>>(&self, input : &'a str) -> IResult<&'a str, HibouBroadcastOrigin,E> {
+        into_generic_result(input, self.parse_broadcast_origin_with_failure(input))
+    }
+}
+
+impl GeneralContext {
+
+    fn parse_lifeline<'a>(&self, input : &'a str) -> InteractionParsingResult<'a,usize> {
+        parse_name(self.get_lf_names(), "a lifeline", input)
+    }
+
+    fn parse_message_with_failure<'a>(&self, input : &'a str) -> InteractionParsingResult<'a,usize> {
+        parse_name(self.get_ms_names(), "a message", input)
+    }
+
+    // either a lifeline or a gate, for the origin as well as for the targets of broadcasts
+    fn parse_broadcast_origin_with_failure<'a>(&self, input : &'a str) -> InteractionParsingResult<'a,HibouBroadcastOrigin> {
         alt(
             (
                 map(
-                    |x| parse_element_of_preexisting_vec_and_return_index(self.get_lf_names(),x),
-                    |l| HibouBroadcastOrigin::LF(l)
+                    |x| self.parse_lifeline(x),
+                    HibouBroadcastOrigin::LF
                 ),
                 map(
-                    |x| parse_element_of_preexisting_vec_and_return_index(self.get_gt_names(),x),
-                    |g| HibouBroadcastOrigin::GT(g)
+                    |x| parse_name(self.get_gt_names(), "a gate", x),
+                    HibouBroadcastOrigin::GT
                 )
             )
         )(input)
     }
 
-    fn parse_single_broadcast_targets<'a, E: ParseError<&'a str>>(&self, input : &'a str) -> IResult<&'a str, HibouBroadcastOrigin,E> {
+    fn parse_broadcast_targets_with_failure<'a>(&self, input : &'a str) -> InteractionParsingResult<'a,Vec<HibouBroadcastOrigin>> {
+        alt(
+            (
+                value(vec![], char(self.get_empty_target_char())),
+                map(|x| self.parse_broadcast_origin_with_failure(x), |y| vec![y]),
+                |x| parse_parenthesized_list(|y| self.parse_broadcast_origin_with_failure(y), true, x)
+            )
+        )(input)
+    }
+
+    fn parse_broadcast_pattern_with_failure<'a>(&self, input : &'a str) -> InteractionParsingResult<'a,HibouBroadcastLeafPattern> {
         alt(
             (
                 map(
-                    |x| parse_element_of_preexisting_vec_and_return_index(self.get_lf_names(),x),
-                    HibouBroadcastOrigin::LF
+                    tuple(
+                        (
+                            |x| self.parse_broadcast_origin_with_failure(x),
+                            multispace0,
+                            |x| parse_symbol(self.get_tag_for_message_transmission_from_origin(),x),
+                            multispace0,
+                            |x| self.parse_message_with_failure(x),
+                            multispace0,
+                            |x| parse_symbol(self.get_tag_for_message_reception_by_target(),x),
+                            multispace0,
+                            |x| self.parse_broadcast_targets_with_failure(x)
+                        )
+                    ),
+                    |(origin,_,_,_,message,_,_,_,targets)| self.make_pattern(Some(origin),message,targets)
                 ),
                 map(
-                    |x| parse_element_of_preexisting_vec_and_return_index(self.get_gt_names(),x),
-                    HibouBroadcastOrigin::GT
+                    tuple(
+                        (
+                            |x| self.parse_message_with_failure(x),
+                            multispace0,
+                            |x| parse_symbol(self.get_tag_for_message_reception_by_target(),x),
+                            multispace0,
+                            |x| self.parse_broadcast_targets_with_failure(x)
+                        )
+                    ),
+                    |(message,_,_,_,targets)| self.make_pattern(None,message,targets)
                 )
             )
         )(input)
     }
-}
 
-impl GeneralContext {
+    fn parse_explicit_pattern_with_failure<'a>(&self, input : &'a str) -> InteractionParsingResult<'a,HibouLeafPattern> {
+        // we have two kinds of patterns :
+        // *m -> l* for the reception of *m* by *l*
+        // *l -- m -> X* for the emission of *m* by *l* to *X*, with *X* itself being either of three patterns:
+        //     *|* for the empty target
+        //     *l2* for another lifeline
+        //     *(l2,l3)* for two or more lifelines
+        alt(
+            (
+                map(|x| self.parse_broadcast_pattern_with_failure(x), HibouLeafPattern::BROADCAST),
+                value(HibouLeafPattern::EMPTY,alt((tag("0"),tag("o"))))
+            )
+        )
+        (input)
+    }
 
-    fn parse_list_of_lifelines<'a, E: ParseError<&'a str>>(&self, input : &'a str) -> IResult<
-        &'a str,
-        Vec<usize>,
-        E> {
-        separated_list0(
-            tuple((tag(","),multispace0)),
-            |x| parse_element_of_preexisting_vec_and_return_index(self.get_lf_names(),x)
+    // *l!m* for the emission of *m* by *l* and *l?m* for its reception by *l*
+    fn parse_trace_action_with_failure<'a>(&self, input : &'a str) -> InteractionParsingResult<'a,TraceAction> {
+        map(
+            tuple(
+                (
+                    |x| self.parse_lifeline(x),
+                    alt(
+                        (
+                            value(TraceActionKind::Emission, |x| parse_symbol("!",x)),
+                            value(TraceActionKind::Reception, |x| parse_symbol("?",x))
+                        )
+                    ),
+                    |x| self.parse_message_with_failure(x)
+                )
+            ),
+            |(lf_id,act_kind,ms_id)| TraceAction::new(lf_id,act_kind,ms_id)
         )(input)
     }
 
-}
-
-
-impl ContextAwareInteractionParser<HibouLangCioII> for GeneralContext {
-
-    fn parse_operator<'a, E: ParseError<&'a str>>(&self, input : &'a str) -> IResult<
-        &'a str,
-        HibouOperators, 
-        E> {
+    fn parse_operator_with_failure<'a>(&self, input : &'a str) -> InteractionParsingResult<'a,HibouOperators> {
         alt(
             (
             value(HibouOperators::Strict, tag("strict")),
             value(HibouOperators::Coreg(vec![]), tag("seq")),
             value(HibouOperators::Coreg(self.get_all_lfs_ids()), tag("par")),
             map(
-                tuple(
-                    (
-                        value( (), tag("coreg")),
-                        multispace0,
-                        value( (), tag("(")),
-                        multispace0,
-                        |x| self.parse_list_of_lifelines(x),
-                        value( (), tag(")")),
-                        )
+                preceded(
+                    tuple((tag("coreg"), multispace0)),
+                    |x| parse_parenthesized_list(|y| self.parse_lifeline(y), false, x)
                 ),
-                |(_,_,_,_,x,_)| HibouOperators::Coreg(x)
+                HibouOperators::Coreg
             ),
             value(HibouOperators::Alt, tag("alt")),
+            value(HibouOperators::And, tag("and")),
             value(HibouOperators::Loop(LoopKind::SStrictSeq), tag("loopS")),
             value(HibouOperators::Loop(LoopKind::HHeadFirstWS), tag("loopH")),
             value(HibouOperators::Loop(LoopKind::Coreg(vec![])), tag("loopW")),
             value(HibouOperators::Loop(LoopKind::Coreg(self.get_all_lfs_ids())), tag("loopP")),
             map(
-                tuple(
-                    (
-                        value( (), tag("loopC")),
-                        multispace0,
-                        value( (), tag("(")),
-                        multispace0,
-                        |x| self.parse_list_of_lifelines(x),
-                        value( (), tag(")")),
-                        )
+                preceded(
+                    tuple((tag("loopC"), multispace0)),
+                    |x| parse_parenthesized_list(|y| self.parse_lifeline(y), false, x)
                 ),
-                |(_,_,_,_,x,_)| HibouOperators::Loop(LoopKind::Coreg(x))
+                |x| HibouOperators::Loop(LoopKind::Coreg(x))
+            ),
+            map(
+                preceded(
+                    tuple((tag("sync"), multispace0)),
+                    |x| parse_parenthesized_list(|y| self.parse_trace_action_with_failure(y), false, x)
+                ),
+                HibouOperators::Sync
             ),
             )
         )
-        (input)
+        (input).map_err(
+            |e| e.map(|failure| {
+                let keywords : Vec<String> = OPERATOR_KEYWORDS.iter().map(|k| k.to_string()).collect();
+                failure.or(InteractionParsingFailure::expecting(input, "an operator", &keywords))
+            })
+        )
     }
 
-    fn parse_explicit_pattern<'a, E: ParseError<&'a str>>(&self, input : &'a str) -> IResult<
+    /**
+     * Parses an interaction, a failure being located at the furthest point the parser could reach
+     * and carrying what was expected there.
+     **/
+    pub(in crate::seqdiag_lib_interface) fn parse_interaction_with_failure<'a>(&self, input : &'a str) -> InteractionParsingResult<'a,InteractionInternalRepresentation<HibouLangCioII>> {
+        delimited(
+            multispace0,
+            alt(
+                (
+                    map(|x| self.parse_explicit_pattern_with_failure(x), InteractionInternalRepresentation::LeafPattern),
+                    map(
+                        tuple(
+                            (
+                                |x| self.parse_operator_with_failure(x),
+                                multispace0,
+                                |x| parse_parenthesized_list(|y| self.parse_interaction_with_failure(y), false, x)
+                            )
+                        ),
+                        |(operator,_,operands)| InteractionInternalRepresentation::Operator(operator,operands)
+                    )
+                )
+            ),
+            multispace0
+        )(input)
+    }
+
+    pub(in crate::seqdiag_lib_interface) fn parse_list_of_lifelines<'a, E: ParseError<&'a str>>(&self, input : &'a str) -> IResult<
         &'a str,
-        HibouLeafPattern,
+        Vec<usize>,
         E> {
-        // we have two kinds of patterns :
-        // *m -> l* for the reception of *m* by *l*
-        // *l -- m -> X* for the emission of *m* by *l* to *X*, with *X* itself being either of three patterns:
-        //     *|* for the empty target
-        //     *l2* for another lifeline
-        //     *(l2,l3)* for two or more lifelines
-        alt(
-            (
-                map(|x| self.parse_broadcast_pattern(x), |y| HibouLeafPattern::BROADCAST(y)),
-                value(HibouLeafPattern::EMPTY,alt((tag("0"),tag("o"))))
-            )
-        )
-        (input)
+        let parsed = parse_separated_list(
+            |x| parse_symbol(",",x),
+            |x| self.parse_lifeline(x),
+            input
+        ).map(|(rem,(lfs,_))| (rem,lfs));
+        into_generic_result(input, parsed)
     }
 
-
+    pub(crate) fn parse_trace_action<'a, E: ParseError<&'a str>>(&self, input : &'a str) -> IResult<
+        &'a str,
+        TraceAction,
+        E> {
+        into_generic_result(input, self.parse_trace_action_with_failure(input))
+    }
 
 }
 
 
+impl ContextAwareInteractionParser<HibouLangCioII> for GeneralContext {
 
+    fn parse_operator<'a, E: ParseError<&'a str>>(&self, input : &'a str) -> IResult<
+        &'a str,
+        HibouOperators, 
+        E> {
+        into_generic_result(input, self.parse_operator_with_failure(input))
+    }
 
+    fn parse_explicit_pattern<'a, E: ParseError<&'a str>>(&self, input : &'a str) -> IResult<
+        &'a str,
+        HibouLeafPattern,
+        E> {
+        into_generic_result(input, self.parse_explicit_pattern_with_failure(input))
+    }
 
-
-
-
+}
@@ -0,0 +1,127 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use common_sequence_diagram_io::from_text::util::parse_utils::parse_label_with_underscores;
+use nom::bytes::complete::tag;
+use nom::character::complete::multispace0;
+use nom::combinator::{all_consuming, map, opt};
+use nom::error::{ParseError, VerboseError};
+use nom::multi::{many0, separated_list0};
+use nom::sequence::{delimited, tuple};
+use nom::IResult;
+
+use crate::core::general_context::GeneralContext;
+
+
+
+// a section is a keyword followed by a list of names e.g. *@lifeline{ l1; l2 }*
+fn parse_signature_section<'a, E: ParseError<&'a str>>(input : &'a str) -> IResult<
+    &'a str,
+    (String,Vec<String>),
+    E> {
+    map(
+        tuple(
+            (
+                tag("@"),
+                parse_label_with_underscores,
+                multispace0,
+                tag("{"),
+                multispace0,
+                separated_list0(
+                    tuple((multispace0,tag(";"),multispace0)),
+                    parse_label_with_underscores
+                ),
+                multispace0,
+                opt(tag(";")),
+                multispace0,
+                tag("}"),
+                multispace0
+            )
+        ),
+        |(_,keyword,_,_,_,names,_,_,_,_,_)| (keyword,names)
+    )(input)
+}
+
+
+
+/**
+ * Parses a signature i.e. the declaration of the lifelines, messages and gates.
+ * Sections may occur in any order and may be omitted.
+ * All the problems (unknown sections, duplicate declarations) are reported at once.
+ **/
+pub(in crate::seqdiag_lib_interface) fn parse_general_context(input : &str) -> Result<GeneralContext,String> {
+    let sections = match all_consuming(
+        delimited(multispace0, many0(parse_signature_section::<VerboseError<&str>>), multispace0)
+    )(input) {
+        Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
+            return Err(nom::error::convert_error(input, e));
+        },
+        Err(nom::Err::Incomplete(_)) => {
+            return Err("incomplete input".to_string());
+        },
+        Ok((_,sections)) => {
+            sections
+        }
+    };
+    // ***
+    let mut errors : Vec<String> = vec![];
+    let mut lf_names : Vec<String> = vec![];
+    let mut ms_names : Vec<String> = vec![];
+    let mut gt_names : Vec<String> = vec![];
+    let mut seen_sections : BTreeSet<String> = BTreeSet::new();
+    for (keyword,names) in sections {
+        let declared = match keyword.as_str() {
+            "lifeline" => {
+                &mut lf_names
+            },
+            "message" => {
+                &mut ms_names
+            },
+            "gate" => {
+                &mut gt_names
+            },
+            _ => {
+                errors.push(format!("unknown section '@{}', expected one of '@lifeline', '@message' or '@gate'", keyword));
+                continue;
+            }
+        };
+        if !seen_sections.insert(keyword.clone()) {
+            errors.push(format!("section '@{}' is declared several times", keyword));
+        }
+        for name in names {
+            if declared.contains(&name) {
+                errors.push(format!("duplicate {} '{}'", keyword, name));
+            } else {
+                declared.push(name);
+            }
+        }
+    }
+    // lifelines and gates may both occur as origins and targets of messages
+    for gt_name in &gt_names {
+        if lf_names.contains(gt_name) {
+            errors.push(format!("'{}' is declared both as a lifeline and as a gate", gt_name));
+        }
+    }
+    // ***
+    if errors.is_empty() {
+        Ok(GeneralContext::new(lf_names, ms_names, gt_names))
+    } else {
+        Err(errors.join("\n"))
+    }
+}
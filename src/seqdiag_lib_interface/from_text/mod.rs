@@ -15,4 +15,7 @@ limitations under the License.
 */
 
 
-mod context_aware_parser;
\ No newline at end of file
+mod context_aware_parser;
+mod trace_parser;
+pub(in crate::seqdiag_lib_interface) mod context_parser;
+pub mod parsing_error;
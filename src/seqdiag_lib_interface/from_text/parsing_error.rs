@@ -0,0 +1,268 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+
+use std::fmt;
+use std::ops::Range;
+
+use nom::error::{ErrorKind, ParseError};
+
+
+
+
+/**
+ * A parsing error located in the input text.
+ * The *span* is a range of bytes in the input while *line* and *column* are counted from 1,
+ * the column being counted in characters.
+ **/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InteractionParsingError {
+    pub span : Range<usize>,
+    pub line : usize,
+    pub column : usize,
+    // what was found at the location of the error, None if we reached the end of the input
+    pub found : Option<String>,
+    pub expected : Vec<String>,
+    // names close to the one which was found that would have been accepted
+    pub suggestions : Vec<String>,
+    // the line of the input in which the error occurs, used for rendering
+    pub source_line : String
+}
+
+impl fmt::Display for InteractionParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let found = match &self.found {
+            None => {
+                "end of input".to_owned()
+            },
+            Some(got) => {
+                format!("'{}'", got)
+            }
+        };
+        write!(f, "unexpected {} at line {}, column {}", found, self.line, self.column)?;
+        if !self.expected.is_empty() {
+            write!(f, ", expected {}", enumerate_with_or(&self.expected))?;
+        }
+        // annotated snippet
+        let line_number = self.line.to_string();
+        let margin = " ".repeat(line_number.len());
+        let caret_num = self.found.as_ref().map_or(1, |got| got.chars().count());
+        write!(f, "\n{} |\n{} | {}\n{} | {}{}", 
+            margin, 
+            line_number, 
+            self.source_line, 
+            margin, 
+            " ".repeat(self.column - 1), 
+            "^".repeat(caret_num)
+        )?;
+        if !self.suggestions.is_empty() {
+            let quoted : Vec<String> = self.suggestions.iter().map(|s| format!("'{}'", s)).collect();
+            write!(f, "\n{} = did you mean {}?", margin, enumerate_with_or(&quoted))?;
+        }
+        Ok(())
+    }
+}
+
+impl InteractionParsingError {
+
+    pub(in crate::seqdiag_lib_interface) fn from_failure(
+        source : &str,
+        failure : &InteractionParsingFailure
+    ) -> InteractionParsingError {
+        let offset = skip_whitespaces(source, source.len() - failure.remaining_len);
+        InteractionParsingError::new(source, offset, failure.expected.clone(), &failure.candidates)
+    }
+
+    pub(in crate::seqdiag_lib_interface) fn from_trailing_input(
+        source : &str,
+        remaining : &str
+    ) -> InteractionParsingError {
+        let offset = skip_whitespaces(source, source.len() - remaining.len());
+        InteractionParsingError::new(source, offset, vec!["end of input".to_owned()], &[])
+    }
+
+    fn new(
+        source : &str,
+        offset : usize,
+        expected : Vec<String>,
+        candidates : &[String]
+    ) -> InteractionParsingError {
+        let line_start = source[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = source[offset..].find('\n').map_or(source.len(), |idx| offset + idx);
+        let line = source[..offset].matches('\n').count() + 1;
+        let column = source[line_start..offset].chars().count() + 1;
+        let rest = &source[offset..];
+        let (found,span_len) = match rest.chars().next() {
+            None => {
+                (None, 0)
+            },
+            Some(c) => {
+                if c.is_alphanumeric() || c == '_' {
+                    let word_len = rest.find(|x : char| !(x.is_alphanumeric() || x == '_')).unwrap_or(rest.len());
+                    (Some(rest[..word_len].to_owned()), word_len)
+                } else if rest.starts_with("--") || rest.starts_with("->") {
+                    (Some(rest[..2].to_owned()), 2)
+                } else {
+                    (Some(c.to_string()), c.len_utf8())
+                }
+            }
+        };
+        let suggestions = match &found {
+            Some(word) if !candidates.contains(word) => {
+                suggest_close_names(word, candidates)
+            },
+            _ => {
+                vec![]
+            }
+        };
+        InteractionParsingError{
+            span : offset..(offset + span_len),
+            line,
+            column,
+            found,
+            expected,
+            suggestions,
+            source_line : source[line_start..line_end].to_owned()
+        }
+    }
+}
+
+
+
+/**
+ * The error type of the parsers of interactions.
+ * When all the branches of a nom alternative fail, their errors are combined with *or*
+ * which retains the failure that occurred the furthest in the input,
+ * this being the most relevant one for the user.
+ * The parsers of names record the names that would have been accepted,
+ * which are used to make suggestions.
+ **/
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(in crate::seqdiag_lib_interface) struct InteractionParsingFailure {
+    // length of the input remaining at the failure
+    pub remaining_len : usize,
+    // descriptions of what was expected at that point e.g. "a lifeline" or "'('"
+    pub expected : Vec<String>,
+    // the names that would have been accepted at that point
+    pub candidates : Vec<String>
+}
+
+impl InteractionParsingFailure {
+
+    pub fn expecting(
+        input : &str,
+        description : &str,
+        candidates : &[String]
+    ) -> InteractionParsingFailure {
+        InteractionParsingFailure{
+            remaining_len : input.len(),
+            expected : vec![description.to_owned()],
+            candidates : candidates.to_vec()
+        }
+    }
+
+}
+
+impl<'a> ParseError<&'a str> for InteractionParsingFailure {
+    fn from_error_kind(input : &'a str, _kind : ErrorKind) -> Self {
+        InteractionParsingFailure{remaining_len : input.len(), expected : vec![], candidates : vec![]}
+    }
+
+    fn append(_input : &'a str, _kind : ErrorKind, other : Self) -> Self {
+        other
+    }
+
+    fn from_char(input : &'a str, c : char) -> Self {
+        InteractionParsingFailure::expecting(input, &format!("'{}'", c), &[])
+    }
+
+    fn or(self, other : Self) -> Self {
+        match self.remaining_len.cmp(&other.remaining_len) {
+            std::cmp::Ordering::Less => {
+                self
+            },
+            std::cmp::Ordering::Greater => {
+                other
+            },
+            std::cmp::Ordering::Equal => {
+                let mut merged = self;
+                for desc in other.expected {
+                    if !merged.expected.contains(&desc) {
+                        merged.expected.push(desc);
+                    }
+                }
+                for name in other.candidates {
+                    if !merged.candidates.contains(&name) {
+                        merged.candidates.push(name);
+                    }
+                }
+                merged
+            }
+        }
+    }
+}
+
+
+
+fn skip_whitespaces(source : &str, offset : usize) -> usize {
+    let rest = &source[offset..];
+    offset + (rest.len() - rest.trim_start().len())
+}
+
+fn edit_distance(s1 : &str, s2 : &str) -> usize {
+    let s2_chars : Vec<char> = s2.chars().collect();
+    let mut previous_row : Vec<usize> = (0..=s2_chars.len()).collect();
+    for (i,c1) in s1.chars().enumerate() {
+        let mut current_row = vec![i + 1];
+        for (j,c2) in s2_chars.iter().enumerate() {
+            let substitution = previous_row[j] + if c1 == *c2 {0} else {1};
+            let insertion = current_row[j] + 1;
+            let deletion = previous_row[j + 1] + 1;
+            current_row.push(substitution.min(insertion).min(deletion));
+        }
+        previous_row = current_row;
+    }
+    previous_row[s2_chars.len()]
+}
+
+const MAX_SUGGESTIONS_NUM : usize = 3;
+
+fn suggest_close_names(word : &str, candidates : &[String]) -> Vec<String> {
+    let max_distance = (word.chars().count() / 3).max(1);
+    let mut close : Vec<(usize,&String)> = candidates.iter()
+        .map(|name| (edit_distance(word, name), name))
+        .filter(|(dist,_)| *dist <= max_distance)
+        .collect();
+    close.sort();
+    close.dedup();
+    close.into_iter().take(MAX_SUGGESTIONS_NUM).map(|(_,name)| name.clone()).collect()
+}
+
+fn enumerate_with_or(items : &[String]) -> String {
+    match items.split_last() {
+        None => {
+            "".to_owned()
+        },
+        Some((last,[])) => {
+            last.clone()
+        },
+        Some((last,firsts)) => {
+            format!("{} or {}", firsts.join(", "), last)
+        }
+    }
+}
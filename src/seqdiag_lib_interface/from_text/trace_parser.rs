@@ -0,0 +1,139 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::multispace0;
+use nom::combinator::{all_consuming, map};
+use nom::error::{ParseError, VerboseError};
+use nom::multi::{many0, separated_list0};
+use nom::sequence::{delimited, terminated, tuple};
+use nom::IResult;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::semantics::multitrace::MultiTrace;
+use crate::core::semantics::trace_action::TraceAction;
+
+
+
+// the lifelines of a component as they are written, and its trace
+type ParsedComponent = (Vec<usize>,Vec<TraceAction>);
+
+impl GeneralContext {
+
+    // a trace is a sequence of actions separated by dots e.g. *l1!m1.l2?m1*
+    fn parse_trace_inner<'a, E: ParseError<&'a str>>(&self, input : &'a str) -> IResult<
+        &'a str,
+        Vec<TraceAction>,
+        E> {
+        separated_list0(
+            tuple((multispace0,tag("."),multispace0)),
+            |x| self.parse_trace_action(x)
+        )(input)
+    }
+
+    // a component is a list of lifelines followed by a trace and terminated by a semicolon
+    // e.g. *[l1,l2] l1!m1.l2?m1;*
+    fn parse_multitrace_component<'a, E: ParseError<&'a str>>(&self, input : &'a str) -> IResult<
+        &'a str,
+        ParsedComponent,
+        E> {
+        map(
+            tuple(
+                (
+                    tag("["),
+                    multispace0,
+                    |x| self.parse_list_of_lifelines(x),
+                    multispace0,
+                    tag("]"),
+                    multispace0,
+                    |x| self.parse_trace_inner(x),
+                    multispace0,
+                    tag(";")
+                )
+            ),
+            |(_,_,lfs,_,_,_,trace,_,_)| (lfs,trace)
+        )(input)
+    }
+
+    // a multi-trace is a list of components between curly brackets
+    fn parse_multitrace_inner<'a, E: ParseError<&'a str>>(&self, input : &'a str) -> IResult<
+        &'a str,
+        Vec<ParsedComponent>,
+        E> {
+        delimited(
+            tuple((tag("{"),multispace0)),
+            many0(terminated(|x| self.parse_multitrace_component(x), multispace0)),
+            tag("}")
+        )(input)
+    }
+
+    pub(in crate::seqdiag_lib_interface) fn parse_trace(&self, input : &str) -> Result<Vec<TraceAction>,String> {
+        match all_consuming(
+            delimited(multispace0, |x| self.parse_trace_inner::<VerboseError<&str>>(x), multispace0)
+        )(input) {
+            Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
+                Err(nom::error::convert_error(input, e))
+            },
+            Err(nom::Err::Incomplete(_)) => {
+                Err("incomplete input".to_string())
+            },
+            Ok((_,trace)) => {
+                Ok(trace)
+            }
+        }
+    }
+
+    pub(in crate::seqdiag_lib_interface) fn parse_multitrace(&self, input : &str) -> Result<MultiTrace,String> {
+        let components = match all_consuming(
+            delimited(multispace0, |x| self.parse_multitrace_inner::<VerboseError<&str>>(x), multispace0)
+        )(input) {
+            Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
+                return Err(nom::error::convert_error(input, e));
+            },
+            Err(nom::Err::Incomplete(_)) => {
+                return Err("incomplete input".to_string());
+            },
+            Ok((_,components)) => {
+                components
+            }
+        };
+        // ***
+        let mut multitrace = MultiTrace::new();
+        let mut seen_lfs : BTreeSet<usize> = BTreeSet::new();
+        for (lfs,trace) in components {
+            for lf_id in &lfs {
+                if !seen_lfs.insert(*lf_id) {
+                    return Err(format!("lifeline {} occurs in several components", self.get_lf_name(*lf_id).unwrap()));
+                }
+            }
+            let lfs : BTreeSet<usize> = lfs.into_iter().collect();
+            for act in &trace {
+                if !lfs.contains(&act.lf_id) {
+                    return Err(format!("action {} does not occur on the lifelines of its component", self.trace_action_as_text(act)));
+                }
+            }
+            // components on the same lifelines, which may only be empty sets of lifelines, would overwrite each other
+            if multitrace.insert(lfs, trace).is_some() {
+                return Err("several components have no lifelines".to_string());
+            }
+        }
+        Ok(multitrace)
+    }
+
+}
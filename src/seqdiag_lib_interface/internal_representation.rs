@@ -16,6 +16,7 @@ limitations under the License.
 
 use common_sequence_diagram_io::internal_representation::{CommonIoInteractionInterface, InteractionOperatorRepresentation};
 
+use crate::core::semantics::trace_action::TraceAction;
 use crate::core::syntax::interaction::LoopKind;
 
 
@@ -75,7 +76,8 @@ pub enum HibouOperators {
     Alt,
     Coreg(Vec<usize>),
     Loop(LoopKind),
-    And
+    And,
+    Sync(Vec<TraceAction>)
 }
 
 impl InteractionOperatorRepresentation for HibouOperators {
@@ -87,6 +89,7 @@ impl InteractionOperatorRepresentation for HibouOperators {
             HibouOperators::Coreg(_) => 2,
             HibouOperators::Loop(_) => 1,
             HibouOperators::And => 2,
+            HibouOperators::Sync(_) => 2,
         }
     }
 
@@ -97,6 +100,7 @@ impl InteractionOperatorRepresentation for HibouOperators {
             HibouOperators::Coreg(_) => true,
             HibouOperators::Loop(_) => false,
             HibouOperators::And => false,
+            HibouOperators::Sync(_) => false,
         }
     }
 }
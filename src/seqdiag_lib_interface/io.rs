@@ -19,14 +19,20 @@ use std::fs;
 use std::path::Path;
 use common_sequence_diagram_io::conversion::lang_to_repr::FromInteractionTermToInternalRepresentation;
 use common_sequence_diagram_io::conversion::repr_to_lang::FromInternalRepresentationToInteractionTerm;
-use common_sequence_diagram_io::from_text::parse::parse_interaction;
+use common_sequence_diagram_io::internal_representation::InteractionInternalRepresentation;
 use common_sequence_diagram_io::to_image::interface::draw_interaction_as_sequence_diagram;
 use common_sequence_diagram_io::to_text::print::print_interaction;
-use crate::rewriting::draw_as_term::draw_interaction_as_term_tree_on_file;
-use crate::seqdiag_lib_interface::internal_representation::HibouLangCioII;
+use crate::rewriting::draw_as_term::{draw_interaction_as_term_tree_on_file, try_draw_interaction_as_term_tree_on_file};
+use crate::seqdiag_lib_interface::internal_representation::{HibouLangCioII, HibouLeafPattern};
+use crate::seqdiag_lib_interface::from_text::context_parser::parse_general_context;
+pub use crate::seqdiag_lib_interface::from_text::parsing_error::InteractionParsingError;
 use crate::core::general_context::GeneralContext;
 use crate::seqdiag_lib_interface::to_image::drawing_context::HibouDrawingContext;
 use crate::core::syntax::interaction::Interaction;
+use crate::core::syntax::validation::{validate, DiagnosticKind};
+use crate::error::HibouError;
+use crate::core::semantics::multitrace::MultiTrace;
+use crate::core::semantics::trace_action::TraceAction;
 
 
 
@@ -35,17 +41,66 @@ pub fn parse_interaction_from_text(
     raw_str_input : &str,
     ctx : &GeneralContext
 ) -> Result<Interaction,String> {
-    match parse_interaction::
-    <HibouLangCioII,GeneralContext>(
-        raw_str_input,
-        ctx
-    ) {
-        Ok(internal_repr) => {
+    try_parse_interaction_from_text(raw_str_input,ctx).map_err(
+        |e| match e {
+            HibouError::Parsing(parsing_error) => {parsing_error.to_string()},
+            other => {other.to_string()}
+        }
+    )
+}
+
+/**
+ * Fallible variant of *parse_interaction_from_text* which distinguishes syntax errors
+ * from broadcasts that have no interaction counterpart (e.g. *m1 -> |* with neither a lifeline origin nor lifeline targets).
+ **/
+pub fn try_parse_interaction_from_text(
+    raw_str_input : &str,
+    ctx : &GeneralContext
+) -> Result<Interaction,HibouError> {
+    match ctx.parse_interaction_with_failure(raw_str_input) {
+        Ok((remaining,internal_repr)) => {
+            if !remaining.trim().is_empty() {
+                return Err(HibouError::Parsing(Box::new(InteractionParsingError::from_trailing_input(raw_str_input,remaining))));
+            }
+            check_internal_representation_is_convertible(&internal_repr,ctx)?;
             let interaction = Interaction::from_io_repr(&internal_repr);
+            check_interaction_elements_are_in_context(ctx,&interaction)?;
             Ok(interaction)
         },
-        Err(e2) => {
-            Err(e2.to_string())
+        Err(nom::Err::Error(failure)) | Err(nom::Err::Failure(failure)) => {
+            Err(HibouError::Parsing(Box::new(InteractionParsingError::from_failure(raw_str_input,&failure))))
+        },
+        Err(nom::Err::Incomplete(_)) => {
+            // the parsers work on complete inputs
+            unreachable!()
+        }
+    }
+}
+
+fn check_internal_representation_is_convertible(
+    internal_repr : &InteractionInternalRepresentation<HibouLangCioII>,
+    ctx : &GeneralContext
+) -> Result<(),HibouError> {
+    match internal_repr {
+        InteractionInternalRepresentation::LeafPattern(HibouLeafPattern::BROADCAST(brd)) => {
+            if brd.lf_targets.is_empty() && !brd.origin.is_lifeline() {
+                let ms_name = ctx.get_ms_name(brd.msg_id).ok_or(HibouError::UnknownMessage(brd.msg_id))?;
+                return Err(
+                    HibouError::UnsupportedPattern(
+                        format!("message {} must be either emitted by a lifeline or received by a lifeline", ms_name)
+                    )
+                );
+            }
+            Ok(())
+        },
+        InteractionInternalRepresentation::LeafPattern(HibouLeafPattern::EMPTY) => {
+            Ok(())
+        },
+        InteractionInternalRepresentation::Operator(_, sub_reprs) => {
+            for sub_repr in sub_reprs {
+                check_internal_representation_is_convertible(sub_repr,ctx)?;
+            }
+            Ok(())
         }
     }
 }
@@ -66,17 +121,181 @@ pub fn read_interaction_from_text_on_file(
     }
 }
 
-pub fn write_interaction_as_text_on_file(
+pub fn try_read_interaction_from_text_on_file(
     file_path : &Path,
+    ctx : &GeneralContext
+) -> Result<Interaction,HibouError> {
+    let data = fs::read_to_string(file_path)?;
+    try_parse_interaction_from_text(&data,ctx)
+}
+
+pub fn interaction_as_text(
     ctx : &GeneralContext,
     int : &Interaction, 
     merge_patterns : bool
-) {
-    let as_txt = print_interaction::<HibouLangCioII,GeneralContext>(
+) -> String {
+    print_interaction::<HibouLangCioII,GeneralContext>(
         &int.to_io_repr(merge_patterns),
         ctx
-    );
-    let _ = fs::write(file_path, as_txt);
+    )
+}
+
+pub fn write_interaction_as_text_on_file(
+    file_path : &Path,
+    ctx : &GeneralContext,
+    int : &Interaction, 
+    merge_patterns : bool
+) {
+    let _ = try_write_interaction_as_text_on_file(file_path, ctx, int, merge_patterns);
+}
+
+pub fn try_write_interaction_as_text_on_file(
+    file_path : &Path,
+    ctx : &GeneralContext,
+    int : &Interaction, 
+    merge_patterns : bool
+) -> Result<(),HibouError> {
+    fs::write(file_path, interaction_as_text(ctx, int, merge_patterns))?;
+    Ok(())
+}
+
+
+
+/**
+ * Signatures declare the lifelines, messages and gates e.g. *@lifeline{ l1; l2 } @message{ m1 }*.
+ **/
+pub fn parse_context_from_text(
+    raw_str_input : &str
+) -> Result<GeneralContext,String> {
+    parse_general_context(raw_str_input)
+}
+
+pub fn read_context_from_file(
+    file_path : &Path
+) -> Result<GeneralContext,String> {
+    match fs::read_to_string(file_path) {
+        Ok(data) => {
+            parse_context_from_text(&data)
+        }
+        Err(e) => {
+            Err(e.to_string())
+        }
+    }
+}
+
+pub fn write_context_to_file(
+    file_path : &Path,
+    ctx : &GeneralContext
+) {
+    let _ = try_write_context_to_file(file_path, ctx);
+}
+
+pub fn try_write_context_to_file(
+    file_path : &Path,
+    ctx : &GeneralContext
+) -> Result<(),HibouError> {
+    fs::write(file_path, ctx.signature_as_text())?;
+    Ok(())
+}
+
+
+
+/**
+ * Traces are written as sequences of actions separated by dots e.g. *l1!m1.l2?m1*.
+ **/
+pub fn parse_trace_from_text(
+    raw_str_input : &str,
+    ctx : &GeneralContext
+) -> Result<Vec<TraceAction>,String> {
+    ctx.parse_trace(raw_str_input)
+}
+
+/**
+ * Multi-traces are written as lists of components between curly brackets,
+ * each component being a list of lifelines followed by a trace and a semicolon
+ * e.g. *{ [l1] l1!m1; [l2,l3] l2?m1.l2!m2.l3?m2; }*.
+ **/
+pub fn parse_multitrace_from_text(
+    raw_str_input : &str,
+    ctx : &GeneralContext
+) -> Result<MultiTrace,String> {
+    ctx.parse_multitrace(raw_str_input)
+}
+
+pub fn trace_as_text(
+    ctx : &GeneralContext,
+    trace : &[TraceAction]
+) -> String {
+    ctx.trace_as_text(trace)
+}
+
+pub fn multitrace_as_text(
+    ctx : &GeneralContext,
+    multitrace : &MultiTrace
+) -> String {
+    ctx.multitrace_as_text(multitrace)
+}
+
+pub fn read_trace_from_text_on_file(
+    file_path : &Path,
+    ctx : &GeneralContext
+) -> Result<Vec<TraceAction>,String> {
+    match fs::read_to_string(file_path) {
+        Ok(data) => {
+            parse_trace_from_text(&data,ctx)
+        }
+        Err(e) => {
+            Err(e.to_string())
+        }
+    }
+}
+
+pub fn read_multitrace_from_text_on_file(
+    file_path : &Path,
+    ctx : &GeneralContext
+) -> Result<MultiTrace,String> {
+    match fs::read_to_string(file_path) {
+        Ok(data) => {
+            parse_multitrace_from_text(&data,ctx)
+        }
+        Err(e) => {
+            Err(e.to_string())
+        }
+    }
+}
+
+pub fn write_trace_as_text_on_file(
+    file_path : &Path,
+    ctx : &GeneralContext,
+    trace : &[TraceAction]
+) {
+    let _ = try_write_trace_as_text_on_file(file_path, ctx, trace);
+}
+
+pub fn try_write_trace_as_text_on_file(
+    file_path : &Path,
+    ctx : &GeneralContext,
+    trace : &[TraceAction]
+) -> Result<(),HibouError> {
+    fs::write(file_path, ctx.trace_as_text(trace))?;
+    Ok(())
+}
+
+pub fn write_multitrace_as_text_on_file(
+    file_path : &Path,
+    ctx : &GeneralContext,
+    multitrace : &MultiTrace
+) {
+    let _ = try_write_multitrace_as_text_on_file(file_path, ctx, multitrace);
+}
+
+pub fn try_write_multitrace_as_text_on_file(
+    file_path : &Path,
+    ctx : &GeneralContext,
+    multitrace : &MultiTrace
+) -> Result<(),HibouError> {
+    fs::write(file_path, ctx.multitrace_as_text(multitrace))?;
+    Ok(())
 }
 
 
@@ -86,6 +305,25 @@ pub enum InteractionDrawingKind {
     AsTermTree
 }
 
+impl InteractionDrawingKind {
+
+    /**
+     * The extension of the files in which interactions are drawn with this kind of drawing.
+     * Sequence diagrams are rendered as bitmaps while term trees are rendered by graphviz as vector images.
+     **/
+    pub fn get_file_extension(&self) -> &'static str {
+        match self {
+            InteractionDrawingKind::AsSequenceDiagram => {
+                "png"
+            },
+            InteractionDrawingKind::AsTermTree => {
+                "svg"
+            }
+        }
+    }
+
+}
+
 
 pub fn draw_interaction_on_file(
     file_path : &Path,
@@ -114,16 +352,72 @@ pub fn draw_interaction_on_file(
 }
 
 
+/**
+ * Fallible variant of *draw_interaction_on_file* which checks beforehand that
+ * all the lifelines, messages and gates of the interaction are declared in the context.
+ * Term trees are drawn with graphviz, which errors are reported.
+ * Sequence diagrams are rendered by a library which does not report errors,
+ * hence they are drawn in a temporary file next to the target, the drawing being deemed to have failed if it is not created.
+ * Only on success is the temporary file moved onto the target so that a pre-existing file is kept otherwise.
+ **/
+pub fn try_draw_interaction_on_file(
+    file_path : &Path,
+    ctx : &GeneralContext,
+    int : &Interaction,
+    draw_kind : &InteractionDrawingKind
+) -> Result<(),HibouError> {
+    check_interaction_elements_are_in_context(ctx,int)?;
+    if file_path.to_str().is_none() {
+        return Err(HibouError::Io(format!("path {:?} is not valid unicode", file_path)));
+    }
+    match draw_kind {
+        InteractionDrawingKind::AsSequenceDiagram => {
+            // the temporary file keeps the extension of the target, from which the image format is inferred
+            let temp_file_path = match file_path.file_name().and_then(|name| name.to_str()) {
+                Some(file_name) => {
+                    file_path.with_file_name(format!("temp_{}", file_name))
+                },
+                None => {
+                    return Err(HibouError::Io(format!("path {:?} is not a file path", file_path)));
+                }
+            };
+            if temp_file_path.exists() {
+                fs::remove_file(&temp_file_path)?;
+            }
+            draw_interaction_on_file(&temp_file_path,ctx,int,draw_kind);
+            if temp_file_path.exists() {
+                fs::rename(&temp_file_path, file_path)?;
+                Ok(())
+            } else {
+                Err(HibouError::Io(format!("could not draw interaction on {:?}", file_path)))
+            }
+        },
+        InteractionDrawingKind::AsTermTree => {
+            try_draw_interaction_as_term_tree_on_file(file_path,ctx,int)
+        }
+    }
+}
 
-
-
-
-
-
-
-
-
-
-
-
-
+/**
+ * Turns the first *Error* diagnostic of the validation of the interaction (if any) into a *HibouError*.
+ **/
+fn check_interaction_elements_are_in_context(
+    ctx : &GeneralContext,
+    int : &Interaction
+) -> Result<(),HibouError> {
+    for diagnostic in validate(int,ctx) {
+        match diagnostic.kind {
+            DiagnosticKind::UnknownLifeline(lf_id) => {
+                return Err(HibouError::UnknownLifeline(lf_id));
+            },
+            DiagnosticKind::UnknownMessage(ms_id) => {
+                return Err(HibouError::UnknownMessage(ms_id));
+            },
+            DiagnosticKind::UnknownGate(gt_id) => {
+                return Err(HibouError::UnknownGate(gt_id));
+            },
+            _ => {}
+        }
+    }
+    Ok(())
+}
@@ -39,6 +39,7 @@ use maplit::hashset;
 use crate::commons::hibou_color_palette::*;
 use crate::commons::{DRAWING_GRAPHIC_FONT,SCALE};
 use crate::core::general_context::GeneralContext;
+use crate::core::semantics::trace_action::TraceActionKind;
 use crate::core::syntax::lang_traits::involve::involves::InvolvesLifelines;
 use crate::seqdiag_lib_interface::internal_representation::{HibouBroadcastOrigin, HibouLangCioII, HibouLeafPattern, HibouOperators};
 use crate::core::syntax::interaction::{Interaction, LoopKind};
@@ -299,6 +300,24 @@ impl ContextAwareInteractionDrawingInstructionsExtractor<HibouLangCioII,usize> f
             HibouOperators::And => {
                 ColoredTextLine::new(vec![("and".to_owned(),Rgb(HCP_BLACK))])
             },
+            HibouOperators::Sync(sync_acts) => {
+                let mut colored_segments = vec![("sync(".to_owned(),Rgb(HCP_BLACK))];
+                let num_sync_acts = sync_acts.len();
+                for (x,sync_act) in sync_acts.iter().enumerate() {
+                    let act_kind_symbol = match sync_act.act_kind {
+                        TraceActionKind::Emission => "!",
+                        TraceActionKind::Reception => "?"
+                    };
+                    colored_segments.push((self.general_context.get_lf_name_or_fallback(sync_act.lf_id),Rgb(HC_LIFELINE)));
+                    colored_segments.push((act_kind_symbol.to_owned(),Rgb(HC_GRAMMAR_SYMBOL)));
+                    colored_segments.push((self.general_context.get_ms_name_or_fallback(sync_act.ms_id),Rgb(HC_MESSAGE)));
+                    if x < num_sync_acts - 1 {
+                        colored_segments.push((",".to_owned(),Rgb(HCP_BLACK)));
+                    }
+                }
+                colored_segments.push((")".to_owned(),Rgb(HCP_BLACK)));
+                ColoredTextLine::new(colored_segments)
+            },
             HibouOperators::Coreg(_) => {
                 panic!("should never be reached")
             }
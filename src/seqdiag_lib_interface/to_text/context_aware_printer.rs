@@ -17,13 +17,30 @@ limitations under the License.
 
 
 
-use common_sequence_diagram_io::conversion::repr_to_lang::FromInternalRepresentationToInteractionTerm;
 use common_sequence_diagram_io::internal_representation::InteractionInternalRepresentation;
 use common_sequence_diagram_io::to_text::context_aware_printer::ContextAwareInteractionPrinter;
 use crate::core::general_context::GeneralContext;
-use crate::core::syntax::lang_traits::involve::involves::InvolvesLifelines;
+use crate::core::semantics::trace_action::{TraceAction, TraceActionKind};
 use crate::seqdiag_lib_interface::internal_representation::{HibouBroadcastOrigin, HibouLangCioII, HibouLeafPattern, HibouOperators};
-use crate::core::syntax::interaction::{Interaction, LoopKind};
+use crate::core::syntax::interaction::LoopKind;
+
+
+impl GeneralContext {
+
+    pub(crate) fn trace_action_as_text(&self, action : &TraceAction) -> String {
+        let symbol = match action.act_kind {
+            TraceActionKind::Emission => "!",
+            TraceActionKind::Reception => "?"
+        };
+        format!(
+            "{}{}{}",
+            self.get_lf_name_or_fallback(action.lf_id),
+            symbol,
+            self.get_ms_name_or_fallback(action.ms_id)
+        )
+    }
+
+}
 
 
 impl ContextAwareInteractionPrinter<HibouLangCioII> for GeneralContext {
@@ -43,7 +60,7 @@ impl ContextAwareInteractionPrinter<HibouLangCioII> for GeneralContext {
     fn print_operator(
         &self, 
         operator : &HibouOperators, 
-        sub_ints : &[InteractionInternalRepresentation<HibouLangCioII>]
+        _sub_ints : &[InteractionInternalRepresentation<HibouLangCioII>]
     ) -> String {
         match operator {
             HibouOperators::Strict => "strict".to_owned(),
@@ -54,14 +71,8 @@ impl ContextAwareInteractionPrinter<HibouLangCioII> for GeneralContext {
                         if cr.is_empty() {
                             "loopW".to_owned()
                         } else {
-                            let involved = {
-                                let unique_sub_int = sub_ints.first().unwrap();
-                                let as_interaction : Interaction = FromInternalRepresentationToInteractionTerm::<HibouLangCioII>::from_io_repr(
-                                    unique_sub_int
-                                );
-                                as_interaction.lifelines_that_may_be_involved()
-                            };
-                            if involved.iter().all(|lf_id| cr.contains(lf_id)) {
+                            // as for "par", "loopP" is parsed back as a coregion over all the lifelines
+                            if self.get_all_lfs_ids().iter().all(|lf_id| cr.contains(lf_id)) {
                                 "loopP".to_owned()
                             } else {
                                 let conc_lfs : Vec<String> = cr.iter().map(
@@ -76,6 +87,10 @@ impl ContextAwareInteractionPrinter<HibouLangCioII> for GeneralContext {
                 }
             },
             HibouOperators::And => "and".to_owned(),
+            HibouOperators::Sync(sync_acts) => {
+                let acts : Vec<String> = sync_acts.iter().map(|act| self.trace_action_as_text(act)).collect();
+                format!("sync({})", acts.join(","))
+            },
             HibouOperators::Coreg(cr) => {
                 if cr.is_empty() {
                     "seq".to_owned()
@@ -0,0 +1,38 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::general_context::GeneralContext;
+
+
+
+impl GeneralContext {
+
+    pub(in crate::seqdiag_lib_interface) fn signature_as_text(&self) -> String {
+        let mut text = String::new();
+        for (keyword,names) in [
+            ("lifeline", self.get_lf_names()),
+            ("message", self.get_ms_names()),
+            ("gate", self.get_gt_names())
+        ] {
+            if !names.is_empty() {
+                text.push_str(&format!("@{}{{\n    {}\n}}\n", keyword, names.join(";\n    ")));
+            }
+        }
+        text
+    }
+
+}
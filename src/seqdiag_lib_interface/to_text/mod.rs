@@ -14,4 +14,6 @@ See the License for the specific language governing permissions and
 limitations under the License.
 */
 
-mod context_aware_printer;
\ No newline at end of file
+mod context_aware_printer;
+mod trace_printer;
+mod context_printer;
\ No newline at end of file
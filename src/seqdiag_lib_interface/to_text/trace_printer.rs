@@ -0,0 +1,41 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::general_context::GeneralContext;
+use crate::core::semantics::multitrace::MultiTrace;
+use crate::core::semantics::trace_action::TraceAction;
+
+
+
+impl GeneralContext {
+
+    pub(in crate::seqdiag_lib_interface) fn trace_as_text(&self, trace : &[TraceAction]) -> String {
+        let acts : Vec<String> = trace.iter().map(|act| self.trace_action_as_text(act)).collect();
+        acts.join(".")
+    }
+
+    pub(in crate::seqdiag_lib_interface) fn multitrace_as_text(&self, multitrace : &MultiTrace) -> String {
+        let mut text = "{\n".to_string();
+        for (lfs,trace) in multitrace {
+            let lf_names : Vec<String> = lfs.iter().map(|lf_id| self.get_lf_name_or_fallback(*lf_id)).collect();
+            text.push_str(&format!("    [{}] {};\n", lf_names.join(","), self.trace_as_text(trace)));
+        }
+        text.push('}');
+        text
+    }
+
+}
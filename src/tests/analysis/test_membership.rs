@@ -0,0 +1,89 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use crate::analysis::check_membership::{check_trace_membership, check_trace_membership_with_param, TraceAnalysisParameterization};
+use crate::analysis::verdict::AnalysisVerdict;
+use crate::core::semantics::trace_action::TraceAction;
+use crate::seqdiag_lib_interface::io::parse_interaction_from_text;
+use crate::tests::canonize::util::get_gen_ctx;
+use crate::tests::semantics::util::naive_accepts;
+use crate::tests::semantics::util::{em, rc};
+
+
+
+
+#[test]
+pub fn test_membership_agrees_with_naive_acceptance() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text(
+        "seq(l1 -- m1 -> l2, alt(l2 -- m2 -> l3, loopS(l1 -- m3 -> |)))",
+        &gen_ctx
+    ).unwrap();
+    let traces : Vec<Vec<TraceAction>> = vec![
+        vec![],
+        vec![em(0,0), rc(1,0)],
+        vec![em(0,0), rc(1,0), em(1,1), rc(2,1)],
+        vec![em(0,0), em(0,2), rc(1,0), em(0,2)],
+        vec![em(0,0), em(1,1)],
+        vec![rc(1,0), em(0,0)],
+    ];
+    for trace in &traces {
+        let expected = if naive_accepts(&int, trace) {
+            AnalysisVerdict::Pass
+        } else {
+            AnalysisVerdict::Fail
+        };
+        assert_eq!(check_trace_membership(&int, trace), expected);
+        for strategy in [QueueSearchStrategy::BFS, QueueSearchStrategy::DFS] {
+            let param = TraceAnalysisParameterization::new(strategy, None, None, false);
+            assert_eq!(check_trace_membership_with_param(&int, trace, param), expected);
+        }
+    }
+}
+
+
+#[test]
+pub fn test_membership_node_budget() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text(
+        "par(loopS(l1 -- m1 -> |), loopS(l1 -- m1 -> |))",
+        &gen_ctx
+    ).unwrap();
+    let trace = vec![em(0,0), em(0,0), em(0,0), em(0,1)];
+    // the trace cannot be accepted but the exploration is truncated before this can be ascertained
+    let param = TraceAnalysisParameterization::new(QueueSearchStrategy::BFS, Some(1), Some(2), false);
+    assert_eq!(check_trace_membership_with_param(&int, &trace, param), AnalysisVerdict::Inconc);
+    // with a tiny memoization cap the exploration still completes
+    let param = TraceAnalysisParameterization::new(QueueSearchStrategy::DFS, Some(1), None, false);
+    assert_eq!(check_trace_membership_with_param(&int, &trace, param), AnalysisVerdict::Fail);
+}
+
+
+#[test]
+pub fn test_membership_of_global_trace_eliminates_no_lifeline() {
+    let gen_ctx = get_gen_ctx();
+    // l2 may be involved in both operands but none of its actions is shared by them
+    let int = parse_interaction_from_text(
+        "and(seq(l2 -- m1 -> |, l1 -- m1 -> |), seq(l2 -- m2 -> |, l1 -- m1 -> |))",
+        &gen_ctx
+    ).unwrap();
+    let trace = vec![em(0,0)];
+    assert!(!naive_accepts(&int, &trace));
+    assert_eq!(check_trace_membership(&int, &trace), AnalysisVerdict::Fail);
+}
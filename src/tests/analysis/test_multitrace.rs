@@ -0,0 +1,82 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+use maplit::{btreemap, btreeset};
+
+use crate::analysis::check_membership::{check_multitrace_membership, check_multitrace_membership_with_param, TraceAnalysisParameterization};
+use crate::analysis::verdict::AnalysisVerdict;
+use crate::core::semantics::multitrace::project_global_trace;
+use crate::seqdiag_lib_interface::io::parse_interaction_from_text;
+use crate::tests::canonize::util::get_gen_ctx;
+use crate::tests::semantics::util::{em, rc};
+
+
+
+
+#[test]
+pub fn test_multitrace_per_lifeline_logs() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text(
+        "seq(l1 -- m1 -> l2, l3 -- m2 -> l2)",
+        &gen_ctx
+    ).unwrap();
+    let ok = btreemap!{
+        btreeset!{0} => vec![em(0,0)],
+        btreeset!{1} => vec![rc(1,0), rc(1,1)],
+        btreeset!{2} => vec![em(2,1)]
+    };
+    assert_eq!(check_multitrace_membership(&int, &ok), AnalysisVerdict::Pass);
+    // l2 must receive m1 before m2
+    let ko = btreemap!{
+        btreeset!{0} => vec![em(0,0)],
+        btreeset!{1} => vec![rc(1,1), rc(1,0)],
+        btreeset!{2} => vec![em(2,1)]
+    };
+    assert_eq!(check_multitrace_membership(&int, &ko), AnalysisVerdict::Fail);
+    // a component that is not entirely consumed is a failure
+    let too_long = btreemap!{
+        btreeset!{0} => vec![em(0,0), em(0,0)],
+        btreeset!{1} => vec![rc(1,0), rc(1,1)],
+        btreeset!{2} => vec![em(2,1)]
+    };
+    assert_eq!(check_multitrace_membership(&int, &too_long), AnalysisVerdict::Fail);
+}
+
+
+#[test]
+pub fn test_multitrace_co_localized_lifelines() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text(
+        "strict(l1 -- m1 -> l2, l2 -- m2 -> l3)",
+        &gen_ctx
+    ).unwrap();
+    let global = vec![em(0,0), rc(1,0), em(1,1), rc(2,1)];
+    let lf_sets = vec![btreeset!{0,1}, btreeset!{2}];
+    let multitrace = project_global_trace(&global, &lf_sets);
+    assert_eq!(multitrace.get(&btreeset!{0,1}).unwrap().len(), 3);
+    for strategy in [QueueSearchStrategy::BFS, QueueSearchStrategy::DFS] {
+        let param = TraceAnalysisParameterization::new(strategy, Some(100), None, false);
+        assert_eq!(check_multitrace_membership_with_param(&int, &multitrace, param), AnalysisVerdict::Pass);
+    }
+    // the emission of m2 cannot be observed before that of m1 on the same component
+    let ko = btreemap!{
+        btreeset!{0,1} => vec![em(1,1), em(0,0), rc(1,0)],
+        btreeset!{2} => vec![rc(2,1)]
+    };
+    assert_eq!(check_multitrace_membership(&int, &ko), AnalysisVerdict::Fail);
+}
@@ -0,0 +1,109 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+use maplit::{btreemap, btreeset};
+
+use crate::analysis::check_membership::{check_multitrace_membership, check_multitrace_membership_with_param, TraceAnalysisParameterization};
+use crate::analysis::verdict::AnalysisVerdict;
+use crate::seqdiag_lib_interface::io::parse_interaction_from_text;
+use crate::tests::canonize::util::get_gen_ctx;
+use crate::tests::semantics::util::{em, rc};
+
+
+fn with_lifeline_removal() -> TraceAnalysisParameterization {
+    TraceAnalysisParameterization::new(QueueSearchStrategy::DFS, Some(1000), None, true)
+}
+
+
+
+#[test]
+pub fn test_unobserved_lifelines_are_eliminated() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text(
+        "seq(l1 -- m1 -> l2, l2 -- m2 -> l3)",
+        &gen_ctx
+    ).unwrap();
+    // l3 is not observed
+    let multitrace = btreemap!{
+        btreeset!{0} => vec![em(0,0)],
+        btreeset!{1} => vec![rc(1,0), em(1,1)]
+    };
+    assert_eq!(check_multitrace_membership(&int, &multitrace), AnalysisVerdict::Pass);
+    let ko = btreemap!{
+        btreeset!{0} => vec![em(0,0)],
+        btreeset!{1} => vec![em(1,1), rc(1,0)]
+    };
+    assert_eq!(check_multitrace_membership(&int, &ko), AnalysisVerdict::Fail);
+}
+
+
+#[test]
+pub fn test_lifeline_removal_weak_pass() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text(
+        "seq(l1 -- m1 -> l2, l2 -- m2 -> l1)",
+        &gen_ctx
+    ).unwrap();
+    // the logging on l1 stopped before the reception of m2
+    let multiprefix = btreemap!{
+        btreeset!{0} => vec![em(0,0)],
+        btreeset!{1} => vec![rc(1,0), em(1,1)]
+    };
+    assert_eq!(check_multitrace_membership(&int, &multiprefix), AnalysisVerdict::Fail);
+    assert_eq!(check_multitrace_membership_with_param(&int, &multiprefix, with_lifeline_removal()), AnalysisVerdict::WeakPass);
+    // a complete multi-trace is still a Pass
+    let complete = btreemap!{
+        btreeset!{0} => vec![em(0,0), rc(0,1)],
+        btreeset!{1} => vec![rc(1,0), em(1,1)]
+    };
+    assert_eq!(check_multitrace_membership_with_param(&int, &complete, with_lifeline_removal()), AnalysisVerdict::Pass);
+    // removing lifelines does not make an erroneous multi-trace acceptable
+    let ko = btreemap!{
+        btreeset!{0} => vec![em(0,0)],
+        btreeset!{1} => vec![em(1,1)]
+    };
+    assert_eq!(check_multitrace_membership_with_param(&int, &ko, with_lifeline_removal()), AnalysisVerdict::Fail);
+}
+
+
+#[test]
+pub fn test_lifeline_removal_before_getting_stuck() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text(
+        "alt(l2 -- m2 -> |, strict(l1 -- m1 -> |, seq(l2 -- m2 -> |, l2 -- m3 -> |)))",
+        &gen_ctx
+    ).unwrap();
+    // nothing was logged on l1, its lifeline must be removed before l2!m2 is consumed
+    // otherwise the analysis only follows the first branch of the alt and gets stuck on l2!m3
+    let multiprefix = btreemap!{
+        btreeset!{0} => vec![],
+        btreeset!{1} => vec![em(1,1), em(1,2)]
+    };
+    assert_eq!(check_multitrace_membership(&int, &multiprefix), AnalysisVerdict::Fail);
+    assert_eq!(check_multitrace_membership_with_param(&int, &multiprefix, with_lifeline_removal()), AnalysisVerdict::WeakPass);
+    // an exhausted component which may still be involved does not prevent a Pass
+    let int = parse_interaction_from_text(
+        "seq(loopS(l1 -- m1 -> |), l2 -- m2 -> |)",
+        &gen_ctx
+    ).unwrap();
+    let complete = btreemap!{
+        btreeset!{0} => vec![em(0,0)],
+        btreeset!{1} => vec![em(1,1)]
+    };
+    assert_eq!(check_multitrace_membership_with_param(&int, &complete, with_lifeline_removal()), AnalysisVerdict::Pass);
+}
@@ -0,0 +1,167 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use autour_core::traits::run::AutRunnable;
+
+use crate::automata::check::{check_equivalence_via_automata, check_inclusion_via_automata};
+use crate::automata::compile::interaction_to_automaton;
+use crate::automata::options::InteractionAutomatonOptions;
+use crate::core::semantics::accepted_traces::accepted_traces;
+use crate::core::semantics::trace_action::TraceAction;
+use crate::equivalence_checker::check_equivalence::{InclusionDirection, InteractionEquivalenceGlobalVerdict};
+#[cfg(feature = "generation")]
+use crate::generation::conf::InteractionGenerationConfig;
+#[cfg(feature = "generation")]
+use crate::generation::generate::generate_interaction_from_seed;
+use crate::inclusion_checker::check_inclusion::InteractionInclusionGlobalVerdict;
+use crate::rewriting::canonize::canonize_interaction;
+use crate::tests::canonize::util::{get_gen_ctx, parse};
+use crate::tests::semantics::util::{em, rc};
+
+
+// all the words of length at most "max_len" over the letters
+fn all_words(letters : &BTreeSet<TraceAction>, max_len : u32) -> Vec<Vec<TraceAction>> {
+    let mut words = vec![vec![]];
+    let mut last = vec![vec![]];
+    for _ in 0..max_len {
+        last = last.iter().flat_map(
+            |word : &Vec<TraceAction>| letters.iter().map(move |letter| [word.clone(),vec![*letter]].concat())
+        ).collect();
+        words.extend(last.iter().cloned());
+    }
+    words
+}
+
+
+
+#[test]
+pub fn test_compile_finite_interaction() {
+    let int = parse("seq(l1 -- m1 -> l2, l2 -- m2 -> l3)");
+    let aut = interaction_to_automaton(&int, &InteractionAutomatonOptions::new(None, false));
+    assert!(aut.is_complete);
+    assert_eq!(aut.states.len(), 5);
+    assert_eq!(aut.states[0], int);
+    assert!(aut.nfa.runs_trace(&[em(0,0),rc(1,0),em(1,1),rc(2,1)]).unwrap());
+    assert!(!aut.nfa.runs_trace(&[em(0,0),em(1,1),rc(1,0),rc(2,1)]).unwrap());
+    assert!(!aut.nfa.runs_trace(&[em(0,0),rc(1,0)]).unwrap());
+    // the sequence is already minimal once the sink state is removed
+    let dfa = aut.to_minimal_dfa();
+    assert!(dfa.runs_trace(&[em(0,0),rc(1,0),em(1,1),rc(2,1)]).unwrap());
+    assert!(dfa.transitions.len() <= 6);
+}
+
+
+#[test]
+pub fn test_compile_loop_with_canonized_states() {
+    let int = parse("loopS(seq(l1 -- m1 -> l2, l2 -- m2 -> l1))");
+    let aut = interaction_to_automaton(&int, &InteractionAutomatonOptions::default());
+    assert!(aut.is_complete);
+    let dfa = aut.to_minimal_dfa();
+    let round = [em(0,0),rc(1,0),em(1,1),rc(0,1)];
+    assert!(dfa.runs_trace(&[]).unwrap());
+    assert!(dfa.runs_trace(&round).unwrap());
+    assert!(dfa.runs_trace(&[round,round,round].concat()).unwrap());
+    assert!(!dfa.runs_trace(&round[..3]).unwrap());
+}
+
+
+#[test]
+pub fn test_compile_infinite_state_space_is_bounded() {
+    // the number of pending receptions is unbounded hence the language is not regular
+    let int = parse("loopP(l1 -- m1 -> l2)");
+    let aut = interaction_to_automaton(&int, &InteractionAutomatonOptions::new(Some(10), true));
+    assert!(!aut.is_complete);
+    assert_eq!(aut.states.len(), 10);
+    // the automaton under-approximates the interaction
+    let letters = BTreeSet::from([em(0,0),rc(1,0)]);
+    let accepted : BTreeSet<Vec<TraceAction>> = accepted_traces(&int, 6, None).collect();
+    for word in all_words(&letters, 6) {
+        if aut.nfa.runs_trace(&word).unwrap() {
+            assert!(accepted.contains(&word));
+        }
+    }
+}
+
+
+#[cfg(feature = "generation")]
+#[test]
+pub fn test_automaton_accepts_the_traces_of_the_interaction() {
+    let ctx = get_gen_ctx();
+    let config = InteractionGenerationConfig::new(3, 0.2, 0.9, Default::default()).unwrap();
+    let options = InteractionAutomatonOptions::new(Some(200), false);
+    for seed in 0..30 {
+        let int = generate_interaction_from_seed(&ctx, &config, seed);
+        let aut = interaction_to_automaton(&int, &options);
+        if !aut.is_complete {
+            continue;
+        }
+        let letters : BTreeSet<TraceAction> = aut.nfa.alphabet.iter().copied().collect();
+        let accepted : BTreeSet<Vec<TraceAction>> = accepted_traces(&int, 3, None).collect();
+        let dfa = aut.to_minimal_dfa();
+        for word in all_words(&letters, 3) {
+            assert_eq!(aut.nfa.runs_trace(&word).unwrap(), accepted.contains(&word));
+            assert_eq!(dfa.runs_trace(&word).unwrap(), accepted.contains(&word));
+        }
+    }
+}
+
+
+#[test]
+pub fn test_inclusion_via_automata() {
+    let strict = parse("strict(l1 -- m1 -> |, l2 -- m2 -> |)");
+    let par = parse("par(l1 -- m1 -> |, l2 -- m2 -> |)");
+    let options = InteractionAutomatonOptions::default();
+    assert_eq!(check_inclusion_via_automata(&strict, &par, &options), InteractionInclusionGlobalVerdict::IsIncluded);
+    assert_eq!(
+        check_inclusion_via_automata(&par, &strict, &options),
+        InteractionInclusionGlobalVerdict::IsNotIncluded(vec![em(1,1),em(0,0)])
+    );
+    assert_eq!(
+        check_equivalence_via_automata(&strict, &par, &options),
+        InteractionEquivalenceGlobalVerdict::NotEquivalent(InclusionDirection::RightNotIncludedInLeft, vec![em(1,1),em(0,0)])
+    );
+    // only the beginning of the state space of the non-regular interaction is explored
+    let non_regular = parse("loopP(l1 -- m1 -> l2)");
+    let bounded = InteractionAutomatonOptions::new(Some(10), true);
+    assert_eq!(
+        check_inclusion_via_automata(&strict, &non_regular, &bounded),
+        InteractionInclusionGlobalVerdict::IsNotIncluded(vec![em(0,0),em(1,1)])
+    );
+    assert_eq!(
+        check_inclusion_via_automata(&non_regular, &parse("loopS(l1 -- m1 -> l2)"), &bounded),
+        InteractionInclusionGlobalVerdict::IsNotIncluded(vec![em(0,0),em(0,0),rc(1,0),rc(1,0)])
+    );
+}
+
+
+#[cfg(feature = "generation")]
+#[test]
+pub fn test_canonization_is_equivalent_via_automata() {
+    let ctx = get_gen_ctx();
+    let config = InteractionGenerationConfig::new(3, 0.2, 0.9, Default::default()).unwrap();
+    let options = InteractionAutomatonOptions::new(Some(200), false);
+    for seed in 0..10 {
+        let int = generate_interaction_from_seed(&ctx, &config, seed);
+        let canonized = canonize_interaction(&int, None, true, true);
+        assert!(!matches!(
+            check_equivalence_via_automata(&int, &canonized, &options),
+            InteractionEquivalenceGlobalVerdict::NotEquivalent(_,_)
+        ));
+    }
+}
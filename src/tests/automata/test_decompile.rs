@@ -0,0 +1,136 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashSet;
+
+use autour_core::bre::bre::ExpBRE;
+use autour_core::bre::term::TermBRE;
+use autour_core::nfa::nfa::AutNFA;
+use maplit::{btreeset, hashmap, hashset};
+
+use crate::automata::check::check_equivalence_via_automata;
+use crate::automata::compile::interaction_to_automaton;
+use crate::automata::decompile::{nfa_to_interaction, nfa_to_regex, regex_term_to_interaction, regex_to_interaction};
+use crate::automata::options::InteractionAutomatonOptions;
+use crate::core::semantics::trace_action::TraceAction;
+use crate::core::syntax::interaction::Interaction;
+use crate::equivalence_checker::check_equivalence::InteractionEquivalenceGlobalVerdict;
+use crate::rewriting::canonize::canonize_interaction;
+use crate::tests::canonize::util::parse;
+use crate::tests::semantics::util::{em, rc};
+
+
+fn assert_equivalent(left : &Interaction, right : &Interaction) {
+    assert_eq!(
+        check_equivalence_via_automata(left, right, &InteractionAutomatonOptions::default()),
+        InteractionEquivalenceGlobalVerdict::Equivalent
+    );
+}
+
+
+
+#[test]
+pub fn test_regex_term_to_interaction() {
+    let em_int = parse("l1 -- m1 ->|");
+    let rc_int = parse("m1 -> l2");
+    assert_eq!(regex_term_to_interaction(&TermBRE::Empty), None);
+    assert_eq!(regex_term_to_interaction(&TermBRE::Epsilon), Some(Interaction::Empty));
+    assert_eq!(regex_term_to_interaction(&TermBRE::Kleene(Box::new(TermBRE::Epsilon))), Some(Interaction::Empty));
+    assert_eq!(regex_term_to_interaction(&TermBRE::Literal(em(0,0))), Some(em_int.clone()));
+    assert_eq!(
+        regex_term_to_interaction(&TermBRE::Concat(vec![TermBRE::Literal(em(0,0)),TermBRE::Literal(rc(1,0)),TermBRE::Epsilon])),
+        Some(Interaction::Strict(Box::new(em_int.clone()), Box::new(Interaction::Strict(Box::new(rc_int.clone()), Box::new(Interaction::Empty)))))
+    );
+    // empty summands are ignored and empty factors are absorbing
+    assert_eq!(
+        regex_term_to_interaction(&TermBRE::Union(btreeset!{TermBRE::Empty,TermBRE::Literal(rc(1,0))})),
+        Some(rc_int.clone())
+    );
+    assert_eq!(
+        regex_term_to_interaction(&TermBRE::Concat(vec![TermBRE::Literal(em(0,0)),TermBRE::Empty])),
+        None
+    );
+}
+
+#[test]
+pub fn test_regex_to_interaction() {
+    let m1 = TermBRE::Concat(vec![TermBRE::Literal(em(0,0)),TermBRE::Literal(rc(1,0))]);
+    let m2 = TermBRE::Concat(vec![TermBRE::Literal(em(1,1)),TermBRE::Literal(rc(2,1))]);
+    let regex = ExpBRE::from_raw(
+        hashset!{em(0,0),rc(1,0),em(1,1),rc(2,1)},
+        TermBRE::Concat(vec![TermBRE::Kleene(Box::new(m1)),m2])
+    ).unwrap();
+    let expected = parse("seq(loopS(l1 -- m1 -> l2), l2 -- m2 -> l3)");
+    let int = regex_to_interaction(&regex).unwrap();
+    assert_eq!(int, canonize_interaction(&expected, None, true, true));
+    assert_equivalent(&int, &expected);
+}
+
+#[test]
+pub fn test_nfa_to_regex() {
+    // two initial states and a state from which no final state can be reached
+    let nfa = AutNFA::from_raw(
+        hashset!{em(0,0),rc(1,0),em(1,1)},
+        hashset!{0,1},
+        hashset!{2},
+        vec![
+            hashmap!{em(0,0) => hashset!{2}, em(1,1) => hashset!{3}},
+            hashmap!{rc(1,0) => hashset!{1,2}},
+            hashmap!{},
+            hashmap!{em(0,0) => hashset!{3}}
+        ]
+    ).unwrap();
+    let expected = TermBRE::Union(btreeset!{
+        TermBRE::Literal(em(0,0)),
+        TermBRE::Concat(vec![TermBRE::Kleene(Box::new(TermBRE::Literal(rc(1,0)))),TermBRE::Literal(rc(1,0))])
+    });
+    assert_eq!(nfa_to_regex(&nfa).term, expected);
+    // no trace is accepted
+    let empty : AutNFA<TraceAction> = AutNFA::from_raw(hashset!{em(0,0)}, hashset!{0}, hashset!{}, vec![hashmap!{em(0,0) => hashset!{0}}]).unwrap();
+    assert_eq!(nfa_to_regex(&empty).term, TermBRE::Empty);
+    assert_eq!(nfa_to_interaction(&empty), None);
+    // only the empty trace is accepted
+    let epsilon : AutNFA<TraceAction> = AutNFA::from_raw(HashSet::new(), hashset!{0}, hashset!{0}, vec![hashmap!{}]).unwrap();
+    assert_eq!(nfa_to_interaction(&epsilon), Some(Interaction::Empty));
+}
+
+#[test]
+pub fn test_interaction_to_automaton_and_back() {
+    let options = InteractionAutomatonOptions::default();
+    // simple interactions are recovered up to canonization
+    for text in [
+        "seq(l1 -- m1 -> l2, l2 -- m2 -> l3)",
+        "alt(l1 -- m1 -> l2, l2 -- m2 -> l3)",
+        "loopS(l1 -- m1 -> l2)"
+    ] {
+        let int = parse(text);
+        let aut = interaction_to_automaton(&int, &options);
+        assert_eq!(nfa_to_interaction(&aut.nfa), Some(canonize_interaction(&int, None, true, true)));
+    }
+    // others are only recovered up to equivalence
+    for text in [
+        "par(l1 -- m1 -> l2, l2 -- m2 -> l3)",
+        "loopH(seq(l1 -- m1 -> l2, l2 -- m2 -> l1))",
+        "loopS(par(l1 -- m1 -> l2, l3 -- m2 -> l2))",
+        "seq(l1 -- m1 -> l2, alt(l2 -- m2 -> l3, loopS(l1 -- m2 -> l3)))"
+    ] {
+        let int = parse(text);
+        let aut = interaction_to_automaton(&int, &options);
+        assert!(aut.is_complete);
+        assert_equivalent(&int, &nfa_to_interaction(&aut.nfa).unwrap());
+    }
+}
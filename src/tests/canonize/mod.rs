@@ -17,4 +17,6 @@ limitations under the License.
 
 pub mod util;
 pub mod test_factorize;
-pub mod test1;
\ No newline at end of file
+pub mod test1;
+pub mod test_summand_inclusion;
+pub mod test_coregion_reordering;
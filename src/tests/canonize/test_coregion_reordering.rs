@@ -0,0 +1,111 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::syntax::interaction::Interaction;
+use crate::rewriting::canonize::{canonize_interaction, canonize_interaction_with_coregion_reordering};
+use crate::rewriting::pipeline::{RewritingPipeline, RewritingPipelinePhase};
+use crate::rewriting::rules::high_level_hibou_rewrite_rules::HighLevelHibouRewriteRules;
+
+use super::util::parse;
+
+
+fn canonize(text : &str) -> Interaction {
+    canonize_interaction_with_coregion_reordering(&parse(text), None, true)
+}
+
+fn get_permutations(elements : &[&'static str]) -> Vec<Vec<&'static str>> {
+    if elements.is_empty() {
+        return vec![vec![]];
+    }
+    let mut permutations = vec![];
+    for (idx,element) in elements.iter().enumerate() {
+        let mut others = elements.to_vec();
+        others.remove(idx);
+        for mut permutation in get_permutations(&others) {
+            permutation.insert(0, element);
+            permutations.push(permutation);
+        }
+    }
+    permutations
+}
+
+
+
+#[test]
+pub fn test_reorder_and_minimize_alone() {
+    let pipeline = RewritingPipeline::new()
+        .with_phase(
+            RewritingPipelinePhase::new(
+                vec![HighLevelHibouRewriteRules::CoregionReorderAndMinimizeModuloAC],
+                None,
+                None
+            )
+        );
+    // no lifeline is shared hence the coregion is useless and the emission goes first
+    let int = parse("coreg(l1,l2,l3)(l1 -- m1 -> l2, l3 -- m2 -> |)");
+    assert_eq!(pipeline.rewrite(&int, None).unwrap(), parse("seq(l3 -- m2 -> |, l1 -- m1 -> l2)"));
+    // l3 -- m2 -> | may go first even though it has to cross two sub-interactions
+    // which do not commute with each other and which are not ordered among themselves
+    let int = parse("seq(l1 -- m3 -> |, l1 -- m1 -> |, l3 -- m2 -> |)");
+    assert_eq!(pipeline.rewrite(&int, None).unwrap(), parse("seq(l3 -- m2 -> |, l1 -- m3 -> |, l1 -- m1 -> |)"));
+}
+
+
+#[test]
+pub fn test_independent_exchanges_canonize_identically() {
+    let exchanges = ["l1 -- m1 -> l2", "l3 -- m2 -> |", "l2 -- m3 -> l1", "l3 -- m1 -> |"];
+    let mut canonized = vec![];
+    for permutation in get_permutations(&exchanges) {
+        let position = |exchange : &str| permutation.iter().position(|x| *x == exchange).unwrap();
+        // the exchanges which share lifelines must keep their relative order
+        if position(exchanges[0]) < position(exchanges[2]) && position(exchanges[1]) < position(exchanges[3]) {
+            let text = format!("seq({})", permutation.join(", "));
+            let got = canonize(&text);
+            if !canonized.contains(&got) {
+                canonized.push(got);
+            }
+        }
+    }
+    assert_eq!(canonized.len(), 1);
+}
+
+
+#[test]
+pub fn test_nested_coregions_canonize_identically() {
+    let got = canonize("seq(l2 -- m3 -> |, coreg(l1)(l1 -- m1 -> |, l3 -- m2 -> |), l1 -- m2 -> |)");
+    assert_eq!(got, canonize("seq(l3 -- m2 -> |, l2 -- m3 -> |, l1 -- m1 -> |, l1 -- m2 -> |)"));
+    let got = canonize("par(seq(l1 -- m1 -> l2, l3 -- m2 -> |), l2 -- m3 -> l3)");
+    assert_eq!(got, canonize("par(l2 -- m3 -> l3, seq(l3 -- m2 -> |, l1 -- m1 -> l2))"));
+}
+
+
+#[test]
+pub fn test_coregion_reordering_is_opt_in() {
+    for phase in RewritingPipeline::full_canonical(true).get_phases() {
+        assert!(!phase.rules.contains(&HighLevelHibouRewriteRules::CoregionReorderAndMinimizeModuloAC));
+        assert!(phase.rules.contains(&HighLevelHibouRewriteRules::ReorderSubInteractionsUnderCoreg));
+        assert!(phase.rules.contains(&HighLevelHibouRewriteRules::CoregionMinimizationBasic));
+    }
+    for phase in RewritingPipeline::full_canonical_with_coregion_reordering().get_phases() {
+        assert!(phase.rules.contains(&HighLevelHibouRewriteRules::CoregionReorderAndMinimizeModuloAC));
+        assert!(!phase.rules.contains(&HighLevelHibouRewriteRules::ReorderSubInteractionsUnderCoreg));
+        assert!(!phase.rules.contains(&HighLevelHibouRewriteRules::CoregionMinimizationBasic));
+    }
+    // the default canonization does not make an emission cross two sub-interactions which do not commute
+    let text = "seq(l1 -- m3 -> |, l1 -- m1 -> |, l3 -- m2 -> |)";
+    assert_ne!(canonize_interaction(&parse(text), None, true, true), canonize(text));
+}
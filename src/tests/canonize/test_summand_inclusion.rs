@@ -0,0 +1,67 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::equivalence_checker::check_equivalence::{check_equivalence_of_interactions, InteractionEquivalenceGlobalVerdict};
+use crate::rewriting::canonize::{canonize_interaction, canonize_interaction_with_summand_inclusion};
+use crate::rewriting::pipeline::{RewritingPipeline, RewritingPipelinePhase};
+use crate::rewriting::rules::high_level_hibou_rewrite_rules::HighLevelHibouRewriteRules;
+
+use super::util::parse;
+
+
+fn get_summand_inclusion_only() -> RewritingPipeline {
+    RewritingPipeline::new()
+        .with_phase(
+            RewritingPipelinePhase::new(
+                vec![HighLevelHibouRewriteRules::SummandInclusionModuloAC],
+                None,
+                None
+            )
+        )
+}
+
+
+
+#[test]
+pub fn test_summand_inclusion_removes_included_summands() {
+    let int = parse("alt(l1 -- m1 -> |, l1 -- m2 -> |, loopS(l1 -- m1 -> |))");
+    let got = get_summand_inclusion_only().rewrite(&int, None).unwrap();
+    assert_eq!(got, parse("alt(l1 -- m2 -> |, loopS(l1 -- m1 -> |))"));
+    // only one of two summands with the same language is removed
+    let int = parse("alt(loopS(l1 -- m1 -> |), loopS(loopS(l1 -- m1 -> |)))");
+    let got = get_summand_inclusion_only().rewrite(&int, None).unwrap();
+    assert_eq!(got, parse("loopS(loopS(l1 -- m1 -> |))"));
+}
+
+
+#[test]
+pub fn test_summand_inclusion_keeps_incomparable_summands() {
+    let int = parse("alt(l1 -- m1 -> |, seq(l1 -- m1 -> |, l1 -- m1 -> |))");
+    assert_eq!(get_summand_inclusion_only().rewrite(&int, None).unwrap(), int);
+}
+
+
+#[test]
+pub fn test_canonize_with_summand_inclusion() {
+    let int = parse("alt(seq(l1 -- m1 -> l2, l2 -- m2 -> l3), seq(l1 -- m1 -> l2, loopS(l2 -- m2 -> l3)))");
+    let without = canonize_interaction(&int, None, true, true);
+    let with = canonize_interaction_with_summand_inclusion(&int, None, true, true);
+    assert_ne!(without, with);
+    // the first summand is included in the second
+    assert_eq!(with, parse("seq(l1 -- m1 -> l2, loopS(l2 -- m2 -> l3))"));
+    assert_eq!(check_equivalence_of_interactions(&int, &with, None), InteractionEquivalenceGlobalVerdict::Equivalent);
+}
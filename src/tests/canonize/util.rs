@@ -15,6 +15,7 @@ limitations under the License.
 */
 
 
+use crate::core::syntax::interaction::Interaction;
 use crate::{core::general_context::GeneralContext, interfaces::HibouGraphvizLoggerParam, rewriting::canonize::canonize_interaction, seqdiag_lib_interface::io::parse_interaction_from_text};
 
 
@@ -95,3 +96,6 @@ pub fn get_gen_ctx() -> GeneralContext {
     )
 }
 
+pub fn parse(text : &str) -> Interaction {
+    parse_interaction_from_text(text, &get_gen_ctx()).unwrap()
+}
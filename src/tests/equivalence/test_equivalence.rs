@@ -0,0 +1,58 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::analysis::check_membership::check_trace_membership;
+use crate::analysis::verdict::AnalysisVerdict;
+use crate::equivalence_checker::check_equivalence::{check_equivalence_of_interactions, InclusionDirection, InteractionEquivalenceGlobalVerdict};
+use crate::tests::canonize::util::parse;
+use crate::tests::semantics::util::em;
+
+
+
+
+#[test]
+pub fn test_equivalent_refactorings() {
+    let original = parse("alt(l1 -- m1 -> l2, l1 -- m2 -> l2)");
+    let refactored = parse("alt(l1 -- m2 -> l2, l1 -- m1 -> l2)");
+    assert_eq!(check_equivalence_of_interactions(&original, &refactored, None), InteractionEquivalenceGlobalVerdict::Equivalent);
+    // ***
+    let original = parse("par(l1 -- m1 -> |, l2 -- m2 -> |)");
+    let refactored = parse("seq(l1 -- m1 -> |, l2 -- m2 -> |)");
+    assert_eq!(check_equivalence_of_interactions(&original, &refactored, None), InteractionEquivalenceGlobalVerdict::Equivalent);
+}
+
+
+#[test]
+pub fn test_not_equivalent_with_direction_and_trace() {
+    let smaller = parse("l1 -- m1 -> |");
+    let bigger = parse("alt(l1 -- m1 -> |, l1 -- m2 -> |)");
+    // ***
+    let verdict = check_equivalence_of_interactions(&bigger, &smaller, None);
+    assert_eq!(verdict, InteractionEquivalenceGlobalVerdict::NotEquivalent(InclusionDirection::LeftNotIncludedInRight, vec![em(0,1)]));
+    // ***
+    match check_equivalence_of_interactions(&smaller, &bigger, None) {
+        InteractionEquivalenceGlobalVerdict::NotEquivalent(direction, trace) => {
+            assert_eq!(direction, InclusionDirection::RightNotIncludedInLeft);
+            // the trace distinguishes the two interactions
+            assert_eq!(check_trace_membership(&bigger, &trace), AnalysisVerdict::Pass);
+            assert_eq!(check_trace_membership(&smaller, &trace), AnalysisVerdict::Fail);
+        },
+        verdict => {
+            panic!("expected non-equivalence, got {}", verdict);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::core::syntax::interaction::Interaction;
+use crate::core::syntax::lang_traits::actions::contains::ContainsTraceActions;
+use crate::core::syntax::validation::validate;
+use crate::equivalence_checker::check_equivalence::{check_equivalence_of_interactions, InteractionEquivalenceGlobalVerdict};
+use crate::error::HibouError;
+use crate::generation::conf::{InteractionGenerationConfig, InteractionGenerationOperatorWeights};
+use crate::generation::generate::{generate_interaction, generate_interaction_from_seed};
+use crate::rewriting::canonize::canonize_interaction;
+use crate::seqdiag_lib_interface::io::parse_context_from_text;
+use crate::tests::canonize::util::get_gen_ctx;
+
+
+
+fn operator_depth(int : &Interaction) -> u32 {
+    match int {
+        Interaction::Empty | Interaction::Emission(_) | Interaction::Reception(_) => {
+            0
+        },
+        // message passings are leaves of the generation
+        Interaction::Strict(i1, i2) if matches!((&**i1,&**i2), (Interaction::Emission(_),Interaction::Reception(_))) => {
+            0
+        },
+        Interaction::Loop(_, i1) => {
+            1 + operator_depth(i1)
+        },
+        Interaction::Strict(i1, i2) |
+        Interaction::CoReg(_, i1, i2) |
+        Interaction::Alt(i1, i2) |
+        Interaction::And(i1, i2) |
+        Interaction::Sync(_, i1, i2) => {
+            1 + operator_depth(i1).max(operator_depth(i2))
+        }
+    }
+}
+
+fn all_operators_weights() -> InteractionGenerationOperatorWeights {
+    InteractionGenerationOperatorWeights{and : 1, sync : 1, ..Default::default()}
+}
+
+
+
+#[test]
+pub fn test_generated_interactions_are_well_formed_and_bounded() {
+    let gen_ctx = parse_context_from_text(
+        "@lifeline{ l1; l2; l3 } @message{ m1; m2; m3 } @gate{ g1 }"
+    ).unwrap();
+    let config = InteractionGenerationConfig::new(5, 0.1, 0.8, all_operators_weights()).unwrap();
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..200 {
+        let int = generate_interaction(&gen_ctx, &config, &mut rng);
+        assert!(operator_depth(&int) <= 5);
+        // only warnings on And may be reported
+        for diagnostic in validate(&int, &gen_ctx) {
+            assert!(!diagnostic.is_error(), "{:?} in {:?}", diagnostic, int);
+        }
+    }
+}
+
+
+#[test]
+pub fn test_generation_is_reproducible_and_configurable() {
+    let gen_ctx = get_gen_ctx();
+    let config = InteractionGenerationConfig::default();
+    for seed in 0..10 {
+        assert_eq!(
+            generate_interaction_from_seed(&gen_ctx, &config, seed),
+            generate_interaction_from_seed(&gen_ctx, &config, seed)
+        );
+    }
+    // without operators, only leaves are generated
+    let no_operators = InteractionGenerationOperatorWeights{
+        strict : 0, seq : 0, coreg : 0, alt : 0,
+        loop_strict : 0, loop_head_first : 0, loop_weak : 0, loop_coreg : 0,
+        and : 0, sync : 0
+    };
+    let config = InteractionGenerationConfig::new(5, 0.0, 1.0, no_operators).unwrap();
+    for seed in 0..10 {
+        let int = generate_interaction_from_seed(&gen_ctx, &config, seed);
+        assert_eq!(operator_depth(&int), 0);
+        assert_ne!(int, Interaction::Empty);
+    }
+    // without actions, all the leaves are empty
+    let config = InteractionGenerationConfig::new(3, 0.0, 0.0, InteractionGenerationOperatorWeights::default()).unwrap();
+    let int = generate_interaction_from_seed(&gen_ctx, &config, 7);
+    assert!(int.get_all_trace_actions().is_empty());
+    assert_eq!(operator_depth(&int), 3);
+}
+
+
+#[test]
+pub fn test_canonization_of_generated_interactions_preserves_semantics() {
+    let gen_ctx = get_gen_ctx();
+    let config = InteractionGenerationConfig::new(3, 0.2, 0.9, InteractionGenerationOperatorWeights::default()).unwrap();
+    for seed in 0..15 {
+        let int = generate_interaction_from_seed(&gen_ctx, &config, seed);
+        let canonized = canonize_interaction(&int, None, true, true);
+        let verdict = check_equivalence_of_interactions(&int, &canonized, None);
+        if let InteractionEquivalenceGlobalVerdict::NotEquivalent(direction,trace) = verdict {
+            panic!("canonization of {:?} into {:?} is not equivalent : {} {:?}", int, canonized, direction, trace)
+        }
+    }
+}
+
+
+#[test]
+pub fn test_generation_probabilities_are_validated() {
+    let weights = InteractionGenerationOperatorWeights::default();
+    assert!(InteractionGenerationConfig::new(3, 0.0, 1.0, weights.clone()).is_ok());
+    assert_eq!(
+        InteractionGenerationConfig::new(3, 1.5, 0.9, weights.clone()),
+        Err(HibouError::InvalidConfiguration("leaf_probability must be between 0 and 1, got 1.5".to_owned()))
+    );
+    assert!(matches!(InteractionGenerationConfig::new(3, 0.2, -0.1, weights.clone()), Err(HibouError::InvalidConfiguration(_))));
+    assert!(matches!(InteractionGenerationConfig::new(3, 0.2, f64::NAN, weights), Err(HibouError::InvalidConfiguration(_))));
+}
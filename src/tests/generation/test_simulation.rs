@@ -0,0 +1,133 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap};
+
+use maplit::hashmap;
+
+use crate::core::syntax::interaction::Interaction;
+use crate::error::HibouError;
+use crate::generation::conf::{InteractionGenerationConfig, TraceSimulationConfig};
+use crate::generation::generate::generate_interaction_from_seed;
+use crate::generation::simulate::simulate_trace_from_seed;
+use crate::tests::canonize::util::{get_gen_ctx, parse};
+use crate::tests::semantics::util::{em, naive_accepts, rc};
+
+
+#[test]
+pub fn test_simulation_is_reproducible() {
+    let ctx = get_gen_ctx();
+    let config = TraceSimulationConfig::default();
+    for seed in 0..20 {
+        let int = generate_interaction_from_seed(&ctx, &InteractionGenerationConfig::default(), seed);
+        assert_eq!(
+            simulate_trace_from_seed(&int, &config, seed),
+            simulate_trace_from_seed(&int, &config, seed)
+        );
+    }
+}
+
+
+#[test]
+pub fn test_simulated_traces_are_accepted() {
+    let ctx = get_gen_ctx();
+    // short traces given that the number of follow-ups may grow exponentially with the length
+    let config = TraceSimulationConfig::new(0.5, HashMap::new(), Some(8)).unwrap();
+    let mut accepted_num = 0;
+    for seed in 0..50 {
+        let int = generate_interaction_from_seed(&ctx, &InteractionGenerationConfig::default(), seed);
+        let simulated = simulate_trace_from_seed(&int, &config, seed);
+        assert_eq!(simulated.is_accepted, naive_accepts(&int, &simulated.trace));
+        if simulated.is_accepted {
+            accepted_num += 1;
+        }
+    }
+    assert!(accepted_num > 25);
+}
+
+
+#[test]
+pub fn test_simulation_alt_branch_weights() {
+    let left = parse("l1 -- m1 -> |");
+    let right = parse("l2 -- m2 -> |");
+    let int = Interaction::Alt(Box::new(left.clone()), Box::new(right.clone()));
+    // ***
+    let config = TraceSimulationConfig::new(0.0, hashmap!{left.clone() => 3}, None).unwrap();
+    let left_num = (0..2000).filter(
+        |seed| simulate_trace_from_seed(&int, &config, *seed).trace == vec![em(0,0)]
+    ).count();
+    assert!((1400..1600).contains(&left_num), "{}", left_num);
+    // ***
+    let config = TraceSimulationConfig::new(0.0, hashmap!{left => 0}, None).unwrap();
+    assert!((0..100).all(|seed| simulate_trace_from_seed(&int, &config, seed).trace == vec![em(1,1)]));
+}
+
+
+#[test]
+pub fn test_simulation_loop_continuation_probability() {
+    let int = parse("loopS(l1 -- m1 -> |)");
+    // ***
+    let never = TraceSimulationConfig::new(0.0, HashMap::new(), None).unwrap();
+    assert_eq!(simulate_trace_from_seed(&int, &never, 0).trace, vec![]);
+    // ***
+    let always = TraceSimulationConfig::new(1.0, HashMap::new(), Some(10)).unwrap();
+    let simulated = simulate_trace_from_seed(&int, &always, 0);
+    assert_eq!(simulated.trace, vec![em(0,0); 10]);
+    assert!(simulated.is_accepted);
+    // the number of iterations follows a geometric distribution which mean is 1 for a probability of 1/2
+    let half = TraceSimulationConfig::new(0.5, HashMap::new(), None).unwrap();
+    let total_len : usize = (0..2000).map(|seed| simulate_trace_from_seed(&int, &half, seed).trace.len()).sum();
+    assert!((1800..2200).contains(&total_len), "{}", total_len);
+}
+
+
+#[test]
+pub fn test_simulation_probability_is_validated() {
+    assert!(matches!(TraceSimulationConfig::new(1.1, HashMap::new(), None), Err(HibouError::InvalidConfiguration(_))));
+    assert!(matches!(TraceSimulationConfig::new(-1.0, HashMap::new(), None), Err(HibouError::InvalidConfiguration(_))));
+}
+
+
+#[test]
+pub fn test_simulation_interrupted() {
+    // l1!m1 is synchronised but can never occur on the right
+    let int = parse("sync(l1!m1)(l1 -- m1 -> |, l2 -- m2 -> |)");
+    let simulated = simulate_trace_from_seed(&int, &TraceSimulationConfig::default(), 0);
+    assert_eq!(simulated.trace, vec![em(1,1)]);
+    assert!(!simulated.is_accepted);
+    // ***
+    let int = parse("strict(l1 -- m1 -> |, l2 -- m2 -> |)");
+    let config = TraceSimulationConfig::new(0.5, HashMap::new(), Some(1)).unwrap();
+    let simulated = simulate_trace_from_seed(&int, &config, 0);
+    assert_eq!(simulated.trace, vec![em(0,0)]);
+    assert!(!simulated.is_accepted);
+}
+
+
+#[test]
+pub fn test_simulated_multitrace() {
+    let ctx = get_gen_ctx();
+    let int = parse("seq(l1 -- m1 -> l2, l2 -- m2 -> l3)");
+    let simulated = simulate_trace_from_seed(&int, &TraceSimulationConfig::default(), 0);
+    assert!(simulated.is_accepted);
+    let multitrace = simulated.as_multitrace(&ctx);
+    assert_eq!(multitrace.get(&BTreeSet::from([0])).unwrap(), &vec![em(0,0)]);
+    assert_eq!(multitrace.get(&BTreeSet::from([1])).unwrap(), &vec![rc(1,0),em(1,1)]);
+    assert_eq!(multitrace.get(&BTreeSet::from([2])).unwrap(), &vec![rc(2,1)]);
+}
+
+
@@ -0,0 +1,55 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+
+use proptest::prelude::*;
+
+use crate::core::syntax::validation::{has_errors, validate};
+use crate::generation::conf::{InteractionGenerationConfig, InteractionGenerationOperatorWeights};
+use crate::generation::strategies::interaction_strategy;
+use crate::seqdiag_lib_interface::io::{interaction_as_text, parse_interaction_from_text};
+use crate::tests::canonize::util::get_gen_ctx;
+
+
+
+proptest! {
+
+    #[test]
+    fn prop_generated_interactions_are_well_formed(
+        int in interaction_strategy(
+            &get_gen_ctx(), 
+            &InteractionGenerationConfig{
+                operator_weights : InteractionGenerationOperatorWeights{and : 1, sync : 1, ..Default::default()},
+                ..Default::default()
+            }
+        )
+    ) {
+        prop_assert!(!has_errors(&validate(&int, &get_gen_ctx())));
+    }
+
+    #[test]
+    fn prop_printing_with_merged_patterns_is_a_fixpoint(
+        int in interaction_strategy(&get_gen_ctx(), &InteractionGenerationConfig::default())
+    ) {
+        let gen_ctx = get_gen_ctx();
+        let printed = interaction_as_text(&gen_ctx, &int, true);
+        let reparsed = parse_interaction_from_text(&printed, &gen_ctx).unwrap();
+        prop_assert_eq!(interaction_as_text(&gen_ctx, &reparsed, true), printed);
+    }
+
+}
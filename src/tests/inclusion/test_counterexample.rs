@@ -0,0 +1,85 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::analysis::check_membership::check_trace_membership;
+use crate::analysis::verdict::AnalysisVerdict;
+use crate::core::semantics::trace_action::TraceAction;
+use crate::inclusion_checker::check_inclusion::{check_inclusion_of_interactions, InteractionInclusionGlobalVerdict};
+use crate::seqdiag_lib_interface::io::parse_interaction_from_text;
+use crate::tests::canonize::util::get_gen_ctx;
+use crate::tests::semantics::util::em;
+
+
+fn get_counterexample(included_text : &str, including_text : &str) -> Vec<TraceAction> {
+    let gen_ctx = get_gen_ctx();
+    let included = parse_interaction_from_text(included_text, &gen_ctx).unwrap();
+    let including = parse_interaction_from_text(including_text, &gen_ctx).unwrap();
+    match check_inclusion_of_interactions(&included, &including, None) {
+        InteractionInclusionGlobalVerdict::IsNotIncluded(counterexample) => {
+            // the counterexample is a behaviour of the included interaction
+            // and it is not a behaviour of the including interaction
+            assert_eq!(check_trace_membership(&included, &counterexample), AnalysisVerdict::Pass);
+            assert_eq!(check_trace_membership(&including, &counterexample), AnalysisVerdict::Fail);
+            counterexample
+        },
+        verdict => {
+            panic!("expected non-inclusion, got {}", verdict);
+        }
+    }
+}
+
+
+
+#[test]
+pub fn test_counterexample_on_unmatched_action() {
+    let counterexample = get_counterexample(
+        "alt(l1 -- m1 -> |, l1 -- m2 -> |)",
+        "l1 -- m1 -> |"
+    );
+    assert_eq!(counterexample, vec![em(0,1)]);
+}
+
+
+#[test]
+pub fn test_counterexample_on_empty_trace() {
+    let counterexample = get_counterexample(
+        "alt(l1 -- m1 -> |, 0)",
+        "l1 -- m1 -> |"
+    );
+    assert_eq!(counterexample, vec![]);
+}
+
+
+#[test]
+pub fn test_counterexample_after_context_simplification() {
+    // the common prefix is factored out during the check
+    // but the counterexample is still a behaviour of the original interactions
+    let counterexample = get_counterexample(
+        "strict(l1 -- m1 -> |, l1 -- m2 -> |)",
+        "strict(l1 -- m1 -> |, l1 -- m3 -> |)"
+    );
+    assert_eq!(counterexample, vec![em(0,0), em(0,1)]);
+}
+
+
+#[test]
+pub fn test_no_counterexample_when_included() {
+    let gen_ctx = get_gen_ctx();
+    let included = parse_interaction_from_text("l1 -- m1 -> |", &gen_ctx).unwrap();
+    let including = parse_interaction_from_text("alt(l1 -- m1 -> |, l1 -- m2 -> |)", &gen_ctx).unwrap();
+    assert_eq!(check_inclusion_of_interactions(&included, &including, None), InteractionInclusionGlobalVerdict::IsIncluded);
+}
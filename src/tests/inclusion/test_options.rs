@@ -0,0 +1,113 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use graph_process_manager_core::queue::strategy::QueueSearchStrategy;
+
+use crate::core::semantics::trace_action::{TraceAction, TraceActionKind};
+use crate::inclusion_checker::check_inclusion::{check_inclusion_of_interactions_with_options, InteractionInclusionGlobalVerdict};
+use crate::inclusion_checker::options::InteractionInclusionCheckingOptions;
+use crate::tests::canonize::util::parse;
+
+
+fn all_option_combinations() -> Vec<InteractionInclusionCheckingOptions> {
+    let mut combinations = vec![];
+    for delayed_alt in [true, false] {
+        for canonize_at_each_step in [true, false] {
+            for memoize in [true, false] {
+                for strategy in [QueueSearchStrategy::BFS, QueueSearchStrategy::DFS] {
+                    combinations.push(
+                        InteractionInclusionCheckingOptions::new(strategy, None, None, delayed_alt, canonize_at_each_step, memoize)
+                    );
+                }
+            }
+        }
+    }
+    combinations
+}
+
+
+
+#[test]
+pub fn test_options_do_not_change_definite_verdicts() {
+    let included = parse("seq(l1 -- m1 -> l2, alt(l2 -- m2 -> l3, l2 -- m3 -> l3))");
+    let including = parse("seq(l1 -- m1 -> l2, l2 -- m2 -> l3)");
+    for options in all_option_combinations() {
+        let verdict = check_inclusion_of_interactions_with_options(&included, &including, &options, None);
+        assert!(matches!(verdict, InteractionInclusionGlobalVerdict::IsNotIncluded(_)));
+        let verdict = check_inclusion_of_interactions_with_options(&including, &included, &options, None);
+        assert_eq!(verdict, InteractionInclusionGlobalVerdict::IsIncluded);
+    }
+}
+
+
+#[test]
+pub fn test_options_explicit_bounds() {
+    let included = parse("strict(l1 -- m1 -> |, loopS(l1 -- m1 -> |))");
+    let including = parse("loopS(l1 -- m1 -> |)");
+    // a node budget truncates the exploration
+    let options = InteractionInclusionCheckingOptions::new(QueueSearchStrategy::BFS, Some(1), None, true, false, true);
+    assert_eq!(
+        check_inclusion_of_interactions_with_options(&included, &including, &options, None),
+        InteractionInclusionGlobalVerdict::IsIncludedUpToExploredSemantics
+    );
+    // so does a low loop instantiation bound
+    let included_loop = parse("loopS(l1 -- m1 -> |)");
+    let including_loop = parse("loopW(l1 -- m1 -> |)");
+    let options = InteractionInclusionCheckingOptions::new(QueueSearchStrategy::BFS, None, Some(0), true, false, true);
+    assert_eq!(
+        check_inclusion_of_interactions_with_options(&included_loop, &including_loop, &options, None),
+        InteractionInclusionGlobalVerdict::IsIncludedUpToExploredSemantics
+    );
+    // in any case no counterexample can be found
+    for options in all_option_combinations() {
+        let verdict = check_inclusion_of_interactions_with_options(&included, &including, &options, None);
+        assert!(!matches!(verdict, InteractionInclusionGlobalVerdict::IsNotIncluded(_)));
+    }
+}
+
+#[test]
+pub fn test_node_budget_does_not_discard_non_inclusion() {
+    // non-inclusion is established early once the common context is factored out
+    // while an exact counterexample requires executing the whole context
+    let included = parse("strict(seq(l1 -- m1 -> l2, l2 -- m2 -> l3, l3 -- m3 -> l1), l1 -- m1 -> |)");
+    let including = parse("strict(seq(l1 -- m1 -> l2, l2 -- m2 -> l3, l3 -- m3 -> l1), l1 -- m2 -> |)");
+    let counterexample = vec![
+        TraceAction::new(0, TraceActionKind::Emission, 0),
+        TraceAction::new(1, TraceActionKind::Reception, 0),
+        TraceAction::new(1, TraceActionKind::Emission, 1),
+        TraceAction::new(2, TraceActionKind::Reception, 1),
+        TraceAction::new(2, TraceActionKind::Emission, 2),
+        TraceAction::new(0, TraceActionKind::Reception, 2),
+        TraceAction::new(0, TraceActionKind::Emission, 0)
+    ];
+    // when the second pass runs out of nodes the possibly inexact counterexample of the first pass is kept
+    for max_node_number in [3, 5] {
+        let options = InteractionInclusionCheckingOptions::new(QueueSearchStrategy::BFS, Some(max_node_number), None, true, false, true);
+        assert_eq!(
+            check_inclusion_of_interactions_with_options(&included, &including, &options, None),
+            InteractionInclusionGlobalVerdict::IsNotIncluded(vec![TraceAction::new(0, TraceActionKind::Emission, 0)])
+        );
+    }
+    // with enough budget the counterexample is exact
+    for max_node_number in [8, 100] {
+        let options = InteractionInclusionCheckingOptions::new(QueueSearchStrategy::BFS, Some(max_node_number), None, true, false, true);
+        assert_eq!(
+            check_inclusion_of_interactions_with_options(&included, &including, &options, None),
+            InteractionInclusionGlobalVerdict::IsNotIncluded(counterexample.clone())
+        );
+    }
+}
@@ -0,0 +1,163 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+
+use maplit::btreeset;
+
+use crate::core::semantics::execute::try_execute_interaction;
+use crate::core::semantics::position::Position;
+use crate::core::syntax::action::EmissionAction;
+use crate::core::syntax::interaction::Interaction;
+use crate::error::HibouError;
+use crate::seqdiag_lib_interface::io::{parse_context_from_text, parse_interaction_from_text, parse_trace_from_text, try_draw_interaction_on_file, try_parse_interaction_from_text, try_read_interaction_from_text_on_file, try_write_context_to_file, try_write_interaction_as_text_on_file, try_write_trace_as_text_on_file, InteractionDrawingKind};
+use crate::tests::canonize::util::get_gen_ctx;
+
+
+
+#[test]
+pub fn test_try_parse_rejects_unsupported_broadcasts() {
+    let gen_ctx = parse_context_from_text(
+        "@lifeline{ l1; l2 } @message{ m1 } @gate{ g1 }"
+    ).unwrap();
+    assert!(try_parse_interaction_from_text("l1 -- m1 -> g1", &gen_ctx).is_ok());
+    // neither emitted nor received by a lifeline
+    for raw in ["m1 -> |", "g1 -- m1 -> |", "g1 -- m1 -> g1", "m1 -> g1"] {
+        match try_parse_interaction_from_text(raw, &gen_ctx) {
+            Err(HibouError::UnsupportedPattern(_)) => {},
+            got => {
+                panic!("unexpected result {:?} for {}", got, raw)
+            }
+        }
+        // the String based API does not panic either
+        assert!(parse_interaction_from_text(raw, &gen_ctx).is_err());
+    }
+    match try_parse_interaction_from_text("seq(l1 -- m1 ->", &gen_ctx) {
+        Err(HibouError::Parsing(_)) => {},
+        got => {
+            panic!("unexpected result {:?}", got)
+        }
+    }
+    let missing = std::env::temp_dir().join("hibou_test_errors_missing_file.hif");
+    let _ = std::fs::remove_file(&missing);
+    match try_read_interaction_from_text_on_file(&missing, &gen_ctx) {
+        Err(HibouError::Io(_)) => {},
+        got => {
+            panic!("unexpected result {:?}", got)
+        }
+    }
+}
+
+
+#[test]
+pub fn test_try_execute_rejects_invalid_positions() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text(
+        "seq(l1 -- m1 -> l2, loopS(l3 -- m2 -> |))",
+        &gen_ctx
+    ).unwrap();
+    let em_pos = Position::Left(Box::new(Position::Left(Box::new(Position::Epsilon))));
+    let got = try_execute_interaction(&int, &em_pos, &btreeset!{0}, true).unwrap();
+    assert_eq!(got.affected_lifelines, btreeset!{0});
+    // a position on an operator, beyond a leaf or through an incompatible operator
+    let invalid_positions = vec![
+        Position::Epsilon,
+        Position::Left(Box::new(Position::Left(Box::new(Position::Left(Box::new(Position::Epsilon)))))),
+        Position::Right(Box::new(Position::Right(Box::new(Position::Epsilon)))),
+        Position::Both(Box::new(Position::Epsilon),Box::new(Position::Epsilon))
+    ];
+    for pos in invalid_positions {
+        match try_execute_interaction(&int, &pos, &btreeset!{}, false) {
+            Err(HibouError::InvalidPosition(got_pos)) => {
+                assert_eq!(got_pos, pos);
+            },
+            _ => {
+                panic!("position {:?} should be rejected", pos)
+            }
+        }
+    }
+}
+
+
+#[test]
+pub fn test_try_draw_rejects_undeclared_elements() {
+    let gen_ctx = get_gen_ctx();
+    let png_path = std::env::temp_dir().join("hibou_test_errors_draw.png");
+    let unknown_lifeline = Interaction::Emission(EmissionAction::new(7, 0, vec![]));
+    assert_eq!(
+        try_draw_interaction_on_file(&png_path, &gen_ctx, &unknown_lifeline, &InteractionDrawingKind::AsSequenceDiagram),
+        Err(HibouError::UnknownLifeline(7))
+    );
+    let unknown_gate = Interaction::Emission(EmissionAction::new(0, 0, vec![0]));
+    assert_eq!(
+        try_draw_interaction_on_file(&png_path, &gen_ctx, &unknown_gate, &InteractionDrawingKind::AsTermTree),
+        Err(HibouError::UnknownGate(0))
+    );
+    let int = parse_interaction_from_text("l1 -- m1 -> l2", &gen_ctx).unwrap();
+    let _ = std::fs::remove_file(&png_path);
+    assert!(try_draw_interaction_on_file(&png_path, &gen_ctx, &int, &InteractionDrawingKind::AsSequenceDiagram).is_ok());
+    assert!(png_path.exists());
+}
+
+
+#[test]
+pub fn test_try_draw_reports_failures() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text("l1 -- m1 -> l2", &gen_ctx).unwrap();
+    let missing_folder = std::env::temp_dir().join("hibou_test_errors_missing_folder");
+    let _ = std::fs::remove_dir_all(&missing_folder);
+    for draw_kind in [InteractionDrawingKind::AsSequenceDiagram, InteractionDrawingKind::AsTermTree] {
+        assert!(matches!(
+            try_draw_interaction_on_file(&missing_folder.join("int.png"), &gen_ctx, &int, &draw_kind),
+            Err(HibouError::Io(_))
+        ));
+    }
+    // a pre-existing file does not hide the failure of the drawing
+    // here the image format cannot be inferred from the extension
+    let stale_path = std::env::temp_dir().join("hibou_test_errors_stale.unknown_format");
+    std::fs::write(&stale_path, "stale").unwrap();
+    assert!(matches!(
+        try_draw_interaction_on_file(&stale_path, &gen_ctx, &int, &InteractionDrawingKind::AsSequenceDiagram),
+        Err(HibouError::Io(_))
+    ));
+    // and is left untouched
+    assert_eq!(std::fs::read_to_string(&stale_path).unwrap(), "stale");
+    let _ = std::fs::remove_file(&stale_path);
+    // whereas a successful drawing replaces it
+    let replaced_path = std::env::temp_dir().join("hibou_test_errors_replaced.png");
+    std::fs::write(&replaced_path, "stale").unwrap();
+    assert!(try_draw_interaction_on_file(&replaced_path, &gen_ctx, &int, &InteractionDrawingKind::AsSequenceDiagram).is_ok());
+    assert_ne!(std::fs::read(&replaced_path).unwrap(), b"stale".to_vec());
+    let _ = std::fs::remove_file(&replaced_path);
+}
+
+
+#[test]
+pub fn test_try_write_reports_failures() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text("l1 -- m1 -> l2", &gen_ctx).unwrap();
+    let trace = parse_trace_from_text("l1!m1.l2?m1", &gen_ctx).unwrap();
+    let missing_folder = std::env::temp_dir().join("hibou_test_errors_missing_folder_for_text");
+    let _ = std::fs::remove_dir_all(&missing_folder);
+    assert!(matches!(try_write_interaction_as_text_on_file(&missing_folder.join("int.hif"), &gen_ctx, &int, true), Err(HibouError::Io(_))));
+    assert!(matches!(try_write_context_to_file(&missing_folder.join("sig.hsf"), &gen_ctx), Err(HibouError::Io(_))));
+    assert!(matches!(try_write_trace_as_text_on_file(&missing_folder.join("trace.htf"), &gen_ctx, &trace), Err(HibouError::Io(_))));
+    // written files can be read back
+    let hif_path = std::env::temp_dir().join("hibou_test_errors_written.hif");
+    assert!(try_write_interaction_as_text_on_file(&hif_path, &gen_ctx, &int, true).is_ok());
+    assert_eq!(try_read_interaction_from_text_on_file(&hif_path, &gen_ctx), Ok(int));
+}
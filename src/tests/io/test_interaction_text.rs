@@ -0,0 +1,156 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+
+use crate::core::syntax::interaction::Interaction;
+use crate::core::general_context::GeneralContext;
+#[cfg(feature = "generation")]
+use crate::generation::conf::{InteractionGenerationConfig, InteractionGenerationOperatorWeights};
+#[cfg(feature = "generation")]
+use crate::generation::generate::generate_interaction_from_seed;
+use crate::seqdiag_lib_interface::io::{interaction_as_text, parse_context_from_text, parse_interaction_from_text};
+
+
+
+fn get_ctx_with_gates() -> GeneralContext {
+    parse_context_from_text(
+        "@lifeline{ l1; l2; l3 } @message{ m1; m2; m3 } @gate{ g1; g2 }"
+    ).unwrap()
+}
+
+/**
+ * The text format flattens associative operators so that the round trip
+ * holds modulo associativity, we therefore compare right-nested forms.
+ **/
+fn nest_associative_operators_to_the_right(int : &Interaction) -> Interaction {
+    match int {
+        Interaction::Strict(i1, i2) => {
+            match nest_associative_operators_to_the_right(i1) {
+                Interaction::Strict(i11, i12) => {
+                    let right = Interaction::Strict(i12, Box::new(*i2.clone()));
+                    nest_associative_operators_to_the_right(&Interaction::Strict(i11, Box::new(right)))
+                },
+                new_i1 => {
+                    Interaction::Strict(Box::new(new_i1), Box::new(nest_associative_operators_to_the_right(i2)))
+                }
+            }
+        },
+        Interaction::Alt(i1, i2) => {
+            match nest_associative_operators_to_the_right(i1) {
+                Interaction::Alt(i11, i12) => {
+                    let right = Interaction::Alt(i12, Box::new(*i2.clone()));
+                    nest_associative_operators_to_the_right(&Interaction::Alt(i11, Box::new(right)))
+                },
+                new_i1 => {
+                    Interaction::Alt(Box::new(new_i1), Box::new(nest_associative_operators_to_the_right(i2)))
+                }
+            }
+        },
+        Interaction::CoReg(cr, i1, i2) => {
+            match nest_associative_operators_to_the_right(i1) {
+                Interaction::CoReg(cr1, i11, i12) if &cr1 == cr => {
+                    let right = Interaction::CoReg(cr.clone(), i12, Box::new(*i2.clone()));
+                    nest_associative_operators_to_the_right(&Interaction::CoReg(cr.clone(), i11, Box::new(right)))
+                },
+                new_i1 => {
+                    Interaction::CoReg(cr.clone(), Box::new(new_i1), Box::new(nest_associative_operators_to_the_right(i2)))
+                }
+            }
+        },
+        Interaction::Loop(lk, i1) => {
+            Interaction::Loop(lk.clone(), Box::new(nest_associative_operators_to_the_right(i1)))
+        },
+        Interaction::And(i1, i2) => {
+            Interaction::And(
+                Box::new(nest_associative_operators_to_the_right(i1)),
+                Box::new(nest_associative_operators_to_the_right(i2))
+            )
+        },
+        Interaction::Sync(sync_acts, i1, i2) => {
+            Interaction::Sync(
+                sync_acts.clone(),
+                Box::new(nest_associative_operators_to_the_right(i1)),
+                Box::new(nest_associative_operators_to_the_right(i2))
+            )
+        },
+        _ => {
+            int.clone()
+        }
+    }
+}
+
+
+
+#[test]
+pub fn test_parse_every_operator() {
+    let gen_ctx = get_ctx_with_gates();
+    let texts = vec![
+        "strict(l1 -- m1 -> |, l2 -- m2 -> |)",
+        "seq(l1 -- m1 -> |, l2 -- m2 -> |)",
+        "par(l1 -- m1 -> |, l2 -- m2 -> |)",
+        "coreg(l1,l3)(l1 -- m1 -> |, l2 -- m2 -> |)",
+        "alt(l1 -- m1 -> |, l2 -- m2 -> |)",
+        "and(l1 -- m1 -> |, alt(l1 -- m1 -> |, l2 -- m2 -> |))",
+        "sync(l1!m1)(l1 -- m1 -> |, l1 -- m1 -> |)",
+        "loopS(l1 -- m1 -> l2)",
+        "loopH(l1 -- m1 -> l2)",
+        "loopW(l1 -- m1 -> l2)",
+        "loopP(l1 -- m1 -> l2)",
+        "loopC(l2)(l1 -- m1 -> l2)",
+        "g1 -- m1 -> (l1,l2)"
+    ];
+    for text in texts {
+        let int = parse_interaction_from_text(text, &gen_ctx).unwrap();
+        let printed = interaction_as_text(&gen_ctx, &int, true);
+        let reparsed = parse_interaction_from_text(&printed, &gen_ctx).unwrap();
+        assert_eq!(reparsed, int, "round trip failed for {} printed as {}", text, printed);
+    }
+    let and_int = parse_interaction_from_text("and(l1 -- m1 -> |, l2 -- m2 -> |)", &gen_ctx).unwrap();
+    assert!(matches!(and_int, Interaction::And(_, _)));
+}
+
+
+#[cfg(feature = "generation")]
+#[test]
+pub fn test_print_parse_round_trip_on_generated_interactions() {
+    let gen_ctx = get_ctx_with_gates();
+    let config = InteractionGenerationConfig::new(
+        4, 
+        0.2, 
+        0.9, 
+        InteractionGenerationOperatorWeights{and : 1, sync : 1, ..Default::default()}
+    ).unwrap();
+    for seed in 0..300 {
+        let int = generate_interaction_from_seed(&gen_ctx, &config, seed);
+        // without merging, the term is parsed back modulo associativity
+        let printed = interaction_as_text(&gen_ctx, &int, false);
+        let reparsed = parse_interaction_from_text(&printed, &gen_ctx)
+            .unwrap_or_else(|e| panic!("could not parse back {} : {}", printed, e));
+        assert_eq!(
+            nest_associative_operators_to_the_right(&reparsed),
+            nest_associative_operators_to_the_right(&int),
+            "round trip failed for {}", printed
+        );
+        // merging may turn e.g. *seq(l1!m1,l1?m1)* into the broadcast *l1 -- m1 -> l1* which is parsed as a strict
+        // so that we only require printing with merged patterns to be a fixpoint
+        let printed_merged = interaction_as_text(&gen_ctx, &int, true);
+        let reparsed_merged = parse_interaction_from_text(&printed_merged, &gen_ctx)
+            .unwrap_or_else(|e| panic!("could not parse back {} : {}", printed_merged, e));
+        assert_eq!(interaction_as_text(&gen_ctx, &reparsed_merged, true), printed_merged);
+    }
+}
@@ -0,0 +1,102 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+
+use crate::error::HibouError;
+use crate::seqdiag_lib_interface::io::{parse_context_from_text, parse_interaction_from_text, try_parse_interaction_from_text, InteractionParsingError};
+use crate::core::general_context::GeneralContext;
+
+
+
+fn get_ctx() -> GeneralContext {
+    parse_context_from_text(
+        "@lifeline{ l1; l2; l3 } @message{ m1; m2; m3 } @gate{ g1 }"
+    ).unwrap()
+}
+
+fn get_parsing_error(text : &str) -> InteractionParsingError {
+    match try_parse_interaction_from_text(text, &get_ctx()) {
+        Err(HibouError::Parsing(parsing_error)) => {
+            *parsing_error
+        },
+        got => {
+            panic!("expected a parsing error for {} but got {:?}", text, got)
+        }
+    }
+}
+
+
+
+#[test]
+pub fn test_parsing_error_unknown_message() {
+    let err = get_parsing_error("seq(l1 -- m4 -> l2)");
+    assert_eq!(err.span, 10..12);
+    assert_eq!((err.line, err.column), (1, 11));
+    assert_eq!(err.found, Some("m4".to_owned()));
+    assert_eq!(err.expected, vec!["a message".to_owned()]);
+    assert_eq!(err.suggestions, vec!["m1".to_owned(), "m2".to_owned(), "m3".to_owned()]);
+    assert_eq!(
+        err.to_string(),
+        "unexpected 'm4' at line 1, column 11, expected a message\n  |\n1 | seq(l1 -- m4 -> l2)\n  |           ^^\n  = did you mean 'm1', 'm2' or 'm3'?"
+    );
+    // the String based API returns the rendered error
+    assert_eq!(parse_interaction_from_text("seq(l1 -- m4 -> l2)", &get_ctx()).unwrap_err(), err.to_string());
+}
+
+
+#[test]
+pub fn test_parsing_error_names_depend_on_location() {
+    let err = get_parsing_error("stirct(l1 -- m1 -> l2)");
+    assert_eq!(err.found, Some("stirct".to_owned()));
+    assert!(err.expected.contains(&"an operator".to_owned()));
+    assert_eq!(err.suggestions, vec!["strict".to_owned()]);
+    // broadcast targets are lifelines or gates
+    let err = get_parsing_error("l1 -- m1 -> (l2,lx)");
+    assert_eq!(err.expected, vec!["a lifeline".to_owned(), "a gate".to_owned()]);
+    assert_eq!(err.suggestions, vec!["l1".to_owned(), "l2".to_owned(), "l3".to_owned()]);
+    // coregions are lists of lifelines
+    let err = get_parsing_error("coreg(l1,l5)(l1 -- m1 -> |)");
+    assert_eq!((err.span.clone(), err.expected.clone()), (9..11, vec!["a lifeline".to_owned()]));
+    // a name that is declared but not of the expected kind is compared to the expected names only
+    let err = get_parsing_error("l1 -- l2 -> l3");
+    assert_eq!(err.found, Some("l2".to_owned()));
+    assert_eq!(err.expected, vec!["a message".to_owned()]);
+    assert_eq!(err.suggestions, vec!["m2".to_owned()]);
+}
+
+
+#[test]
+pub fn test_parsing_error_location_and_expected_tokens() {
+    let err = get_parsing_error("seq(\n  l1 -- m1 -> l2,\n  l2 -- m2 => l3\n)");
+    assert_eq!((err.line, err.column), (3, 12));
+    assert_eq!(err.found, Some("=".to_owned()));
+    assert_eq!(err.expected, vec!["'->'".to_owned()]);
+    assert_eq!(err.source_line, "  l2 -- m2 => l3");
+    // missing closing parenthesis
+    let err = get_parsing_error("seq(l1 -- m1 -> l2");
+    assert_eq!(err.found, None);
+    assert_eq!(err.span, 18..18);
+    assert_eq!(err.expected, vec!["','".to_owned(), "')'".to_owned()]);
+    // trailing input
+    let err = get_parsing_error("l1 -- m1 -> l2 )");
+    assert_eq!(err.found, Some(")".to_owned()));
+    assert_eq!(err.expected, vec!["end of input".to_owned()]);
+    // malformed arrow
+    let err = get_parsing_error("l1 - m1 -> l2");
+    assert_eq!(err.expected, vec!["'--'".to_owned()]);
+}
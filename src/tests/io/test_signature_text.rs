@@ -0,0 +1,60 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::general_context::GeneralContext;
+use crate::seqdiag_lib_interface::io::{parse_context_from_text, read_context_from_file, write_context_to_file};
+use crate::tests::canonize::util::get_gen_ctx;
+
+
+
+#[test]
+pub fn test_signature_parse_and_print() {
+    let gen_ctx = parse_context_from_text(
+        "@message{ m1; m2 }\n@lifeline{\n  l1;\n  l2;\n}\n@gate{ g1 }"
+    ).unwrap();
+    assert_eq!(gen_ctx, GeneralContext::new(
+        vec!["l1".to_string(), "l2".to_string()],
+        vec!["m1".to_string(), "m2".to_string()],
+        vec!["g1".to_string()]
+    ));
+    // ***
+    let sig_path = std::env::temp_dir().join("hibou_test_signature.hsf");
+    write_context_to_file(&sig_path, &gen_ctx);
+    assert_eq!(read_context_from_file(&sig_path).unwrap(), gen_ctx);
+    // ***
+    assert_eq!(parse_context_from_text("").unwrap(), GeneralContext::new(vec![], vec![], vec![]));
+    // the textual signature of the fixture used throughout the tests
+    assert_eq!(
+        parse_context_from_text("@lifeline{ l1; l2; l3 } @message{ m1; m2; m3 }").unwrap(),
+        get_gen_ctx()
+    );
+}
+
+
+#[test]
+pub fn test_signature_errors() {
+    let err = parse_context_from_text(
+        "@lifeline{ l1; l2; l1 } @message{ m1 } @messages{ m2 } @gate{ l2 } @message{ m3 }"
+    ).unwrap_err();
+    assert!(err.contains("duplicate lifeline 'l1'"));
+    assert!(err.contains("unknown section '@messages'"));
+    assert!(err.contains("'l2' is declared both as a lifeline and as a gate"));
+    assert!(err.contains("section '@message' is declared several times"));
+    // syntax errors
+    assert!(parse_context_from_text("@lifeline{ l1 l2 }").is_err());
+    assert!(parse_context_from_text("@lifeline{ l1; ").is_err());
+}
@@ -0,0 +1,95 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use maplit::{btreemap, btreeset};
+
+use crate::analysis::check_membership::check_multitrace_membership;
+use crate::analysis::verdict::AnalysisVerdict;
+use crate::seqdiag_lib_interface::io::{multitrace_as_text, parse_interaction_from_text, parse_multitrace_from_text, parse_trace_from_text, read_multitrace_from_text_on_file, trace_as_text, write_multitrace_as_text_on_file};
+use crate::tests::canonize::util::get_gen_ctx;
+use crate::tests::semantics::util::{em, rc};
+
+
+
+
+#[test]
+pub fn test_trace_parse_and_print() {
+    let gen_ctx = get_gen_ctx();
+    let trace = parse_trace_from_text(" l1!m1 . l2?m1.l2!m2 ", &gen_ctx).unwrap();
+    assert_eq!(trace, vec![em(0,0), rc(1,0), em(1,1)]);
+    assert_eq!(trace_as_text(&gen_ctx, &trace), "l1!m1.l2?m1.l2!m2");
+    assert_eq!(parse_trace_from_text("", &gen_ctx).unwrap(), vec![]);
+    // unknown names and dangling separators are rejected
+    assert!(parse_trace_from_text("l4!m1", &gen_ctx).is_err());
+    assert!(parse_trace_from_text("l1!m1.", &gen_ctx).is_err());
+    // undeclared ids are printed with a fallback name
+    assert_eq!(trace_as_text(&gen_ctx, &[em(7,0), rc(1,9)]), "lf#7!m1.l2?ms#9");
+    assert!(parse_trace_from_text("lf#7!m1", &gen_ctx).is_err());
+    // including in multi-traces
+    let multitrace = btreemap!{btreeset!{0,7} => vec![em(7,0)]};
+    assert_eq!(multitrace_as_text(&gen_ctx, &multitrace), "{\n    [l1,lf#7] lf#7!m1;\n}");
+}
+
+
+#[test]
+pub fn test_multitrace_parse_and_print() {
+    let gen_ctx = get_gen_ctx();
+    let multitrace = parse_multitrace_from_text(
+        "{\n  [l1] l1!m1;\n  [l2,l3] l2?m1.l2!m2.l3?m2;\n}",
+        &gen_ctx
+    ).unwrap();
+    assert_eq!(multitrace, btreemap!{
+        btreeset!{0} => vec![em(0,0)],
+        btreeset!{1,2} => vec![rc(1,0), em(1,1), rc(2,1)]
+    });
+    let printed = multitrace_as_text(&gen_ctx, &multitrace);
+    assert_eq!(parse_multitrace_from_text(&printed, &gen_ctx).unwrap(), multitrace);
+    // empty components are allowed
+    let with_empty = parse_multitrace_from_text("{[l1] l1!m1; [l2] ;}", &gen_ctx).unwrap();
+    assert_eq!(with_empty.get(&btreeset!{1}).unwrap(), &vec![]);
+    // components must be terminated and enclosed in curly brackets
+    assert!(parse_multitrace_from_text("{[l1] l1!m1}", &gen_ctx).is_err());
+    assert!(parse_multitrace_from_text("[l1] l1!m1;", &gen_ctx).is_err());
+    // a lifeline may belong to a single component
+    assert!(parse_multitrace_from_text("{[l1] ; [l1,l2] ;}", &gen_ctx).is_err());
+    assert!(parse_multitrace_from_text("{[l1] ; [l1] ;}", &gen_ctx).is_err());
+    // hence a single component may have no lifelines
+    assert!(parse_multitrace_from_text("{[] ; [l1] ;}", &gen_ctx).is_ok());
+    assert!(parse_multitrace_from_text("{[] ; [l1] ; [] ;}", &gen_ctx).is_err());
+    // actions must occur on the lifelines of their component
+    assert!(parse_multitrace_from_text("{[l1] l2?m1;}", &gen_ctx).is_err());
+}
+
+
+#[test]
+pub fn test_multitrace_file_analysis() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text(
+        "seq(l1 -- m1 -> l2, l2 -- m2 -> l3)",
+        &gen_ctx
+    ).unwrap();
+    let multitrace = btreemap!{
+        btreeset!{0} => vec![em(0,0)],
+        btreeset!{1} => vec![rc(1,0), em(1,1)],
+        btreeset!{2} => vec![rc(2,1)]
+    };
+    let htf_path = std::env::temp_dir().join("hibou_test_multitrace.htf");
+    write_multitrace_as_text_on_file(&htf_path, &gen_ctx, &multitrace);
+    let got = read_multitrace_from_text_on_file(&htf_path, &gen_ctx).unwrap();
+    assert_eq!(got, multitrace);
+    assert_eq!(check_multitrace_membership(&int, &got), AnalysisVerdict::Pass);
+}
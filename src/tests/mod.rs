@@ -16,5 +16,14 @@ limitations under the License.
 
 
 pub mod canonize;
+pub mod semantics;
+pub mod analysis;
+pub mod io;
+pub mod inclusion;
+pub mod equivalence;
+pub mod rewriting;
+#[cfg(feature = "generation")]
+pub mod generation;
+pub mod automata;
 
 
@@ -0,0 +1,132 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashMap;
+
+use maplit::{btreeset, hashmap};
+
+use crate::core::syntax::interaction::Interaction;
+use crate::rewriting::canonize::{canonize_interaction, canonize_interaction_to_all_irreducible_forms};
+use crate::rewriting::confluence::find_critical_pairs;
+use crate::rewriting::derivation::{RewriteRuleApplication, RewritingDerivationGraph};
+use crate::rewriting::pipeline::{RewritingPipeline, RewritingPipelinePhase};
+use crate::rewriting::rules::high_level_hibou_rewrite_rules::HighLevelHibouRewriteRules;
+use crate::tests::canonize::util::parse;
+
+
+// both alternatives share a prefix and a suffix so that factorizing either one first
+// yields differently associated sequences
+fn get_int_with_common_prefix_and_suffix() -> Interaction {
+    parse("alt(seq(l1 -- m1 -> l2, l2 -- m2 -> l3, l3 -- m3 -> l1), seq(l1 -- m1 -> l2, l3 -- m2 -> l2, l3 -- m3 -> l1))")
+}
+
+fn get_factorization_rules() -> Vec<HighLevelHibouRewriteRules> {
+    vec![HighLevelHibouRewriteRules::FactorizeLeft, HighLevelHibouRewriteRules::FactorizeRight]
+}
+
+
+
+#[test]
+pub fn test_all_irreducible_forms_with_rule_paths() {
+    let int = get_int_with_common_prefix_and_suffix();
+    let pipeline = RewritingPipeline::new()
+        .with_phase(RewritingPipelinePhase::new(get_factorization_rules(), None, None));
+    let forms = pipeline.rewrite_to_all_irreducible_forms(&int, None).unwrap();
+    assert_eq!(forms.len(), 2);
+    assert_ne!(forms[0].interaction, forms[1].interaction);
+    for form in &forms {
+        assert_eq!(form.rule_path.len(), 2);
+        assert!(matches!(form.interaction, Interaction::CoReg(_,_,_)));
+    }
+    // each form is reached by factorizing on one side at the root first
+    let first_rules : Vec<HighLevelHibouRewriteRules> = forms.iter().map(|form| form.rule_path[0].rule).collect();
+    assert!(first_rules.contains(&HighLevelHibouRewriteRules::FactorizeLeft));
+    assert!(first_rules.contains(&HighLevelHibouRewriteRules::FactorizeRight));
+    // the single result rewriting keeps one of them
+    let rewritten = pipeline.rewrite(&int, None).unwrap();
+    assert!(forms.iter().any(|form| form.interaction == rewritten));
+}
+
+
+#[test]
+pub fn test_critical_pair_of_factorizations() {
+    let int = get_int_with_common_prefix_and_suffix();
+    let diagnostics = find_critical_pairs(&get_factorization_rules(), &int, None);
+    assert_eq!(diagnostics.len(), 1);
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic.source, int);
+    let applications = [&diagnostic.left_application, &diagnostic.right_application];
+    assert!(applications.contains(&&RewriteRuleApplication::new(HighLevelHibouRewriteRules::FactorizeLeft, vec![])));
+    assert!(applications.contains(&&RewriteRuleApplication::new(HighLevelHibouRewriteRules::FactorizeRight, vec![])));
+    assert_ne!(diagnostic.left_irreducible_form, diagnostic.right_irreducible_form);
+}
+
+
+#[test]
+pub fn test_no_critical_pair_with_flushing() {
+    let int = get_int_with_common_prefix_and_suffix();
+    let mut rules = get_factorization_rules();
+    rules.push(HighLevelHibouRewriteRules::AltAndCoregFlushRight);
+    assert!(find_critical_pairs(&rules, &int, None).is_empty());
+    // the canonization reaches a single form
+    let forms = canonize_interaction_to_all_irreducible_forms(&int, None, true);
+    assert_eq!(forms.len(), 1);
+    assert_eq!(forms[0].interaction, canonize_interaction(&int, None, true, true));
+    assert!(!forms[0].rule_path.is_empty());
+}
+
+
+#[test]
+pub fn test_irreducible_forms_without_phases() {
+    let int = get_int_with_common_prefix_and_suffix();
+    let forms = RewritingPipeline::new().rewrite_to_all_irreducible_forms(&int, None).unwrap();
+    assert_eq!(forms.len(), 1);
+    assert_eq!(forms[0].interaction, int);
+    assert!(forms[0].rule_path.is_empty());
+}
+
+
+#[test]
+pub fn test_reachable_final_nodes_in_cyclic_graph() {
+    // 0 and 1 rewrite into one another, 0 may also reach the final node 2
+    // 3 leads into the cycle and 4 is a final node which is only reachable from 5
+    let interactions : HashMap<u32,Interaction> = (0..6).map(|id| (id,parse(&format!("l1 -- m1 -> l{}", 1 + (id % 3))))).collect();
+    let graph = RewritingDerivationGraph{
+        initial_node_id : 3,
+        interactions,
+        steps : hashmap!{
+            0 => vec![(None,1),(None,2)],
+            1 => vec![(None,0)],
+            3 => vec![(None,1)],
+            5 => vec![(None,4)]
+        },
+        final_nodes : vec![2,4],
+        result_node_id : 2
+    };
+    let reachable = graph.get_reachable_final_nodes();
+    assert_eq!(
+        reachable,
+        hashmap!{
+            0 => btreeset!{2},
+            1 => btreeset!{2},
+            2 => btreeset!{2},
+            3 => btreeset!{2},
+            4 => btreeset!{4},
+            5 => btreeset!{4}
+        }
+    );
+}
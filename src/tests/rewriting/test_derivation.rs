@@ -0,0 +1,99 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::general_context::GeneralContext;
+use crate::rewriting::canonize::{canonize_interaction, canonize_interaction_with_derivation};
+use crate::seqdiag_lib_interface::io::InteractionDrawingKind;
+use crate::tests::canonize::util::{get_gen_ctx, parse};
+
+
+
+
+#[test]
+pub fn test_derivation_explains_canonization() {
+    let int = parse("alt(seq(l1 -- m1 -> l2, l2 -- m2 -> l3), seq(0, l1 -- m1 -> l2, l2 -- m3 -> l3))");
+    let derivation = canonize_interaction_with_derivation(&int, None, true, true);
+    assert_eq!(derivation.initial, int);
+    assert_eq!(derivation.get_result(), &canonize_interaction(&int, None, true, true));
+    assert!(!derivation.steps.is_empty());
+    // the steps are chained
+    let mut current = &derivation.initial;
+    for step in &derivation.steps {
+        assert_eq!(&step.before, current);
+        assert_ne!(step.before, step.after);
+        current = &step.after;
+    }
+    let rule_descs : Vec<&str> = derivation.steps.iter().map(|step| step.rule_desc.as_str()).collect();
+    assert!(rule_descs.contains(&"EpsilonNeutral"));
+    assert!(rule_descs.contains(&"FactorizeLeft"));
+}
+
+
+#[test]
+pub fn test_derivation_of_canonical_interaction() {
+    let int = parse("seq(l1 -- m1 -> l2, l2 -- m2 -> l3)");
+    let derivation = canonize_interaction_with_derivation(&int, None, true, true);
+    assert!(derivation.steps.is_empty());
+    assert_eq!(derivation.get_result(), &int);
+    // a canonical form may still be de-factorized and then factorized back
+    let int = canonize_interaction(&parse("seq(l1 -- m1 -> l2, alt(l2 -- m2 -> l3, l2 -- m3 -> l3))"), None, true, true);
+    let derivation = canonize_interaction_with_derivation(&int, None, true, true);
+    assert_eq!(derivation.get_result(), &int);
+}
+
+
+#[test]
+pub fn test_derivation_export() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse("seq(0, l1 -- m1 -> l2)");
+    let derivation = canonize_interaction_with_derivation(&int, None, true, true);
+    assert_eq!(derivation.steps.len(), 1);
+    // as text
+    let text = derivation.as_text(&gen_ctx);
+    assert!(text.starts_with("initial:\n"));
+    assert!(text.contains("step 1 : EpsilonNeutral at position []\n"));
+    // as json
+    let json = derivation.as_json(&gen_ctx);
+    assert!(json.starts_with("{\"initial\":\""));
+    assert!(json.contains("\"rule\":\"EpsilonNeutral\",\"position\":[]"));
+    assert!(!json.contains('\n'));
+    // control characters in names are escaped
+    let ctrl_ctx = GeneralContext::new(
+        vec!["l\u{1}1".to_string(), "l2".to_string(), "l3".to_string()],
+        gen_ctx.get_ms_names().clone(),
+        vec![]
+    );
+    let json = derivation.as_json(&ctrl_ctx);
+    assert!(json.contains("l\\u00011"));
+    assert!(!json.chars().any(|c| c < '\u{20}'));
+    // as diagrams
+    let folder = std::env::temp_dir();
+    let paths = derivation.draw_steps_on_files(&gen_ctx, &folder, "hibou_test_derivation", &InteractionDrawingKind::AsSequenceDiagram);
+    assert_eq!(paths.len(), 2);
+    for path in paths {
+        assert!(path.exists());
+        let _ = std::fs::remove_file(path);
+    }
+    // term trees are not drawn in png files
+    let paths = derivation.draw_steps_on_files(&gen_ctx, &folder, "hibou_test_derivation_term", &InteractionDrawingKind::AsTermTree);
+    assert_eq!(paths.len(), 2);
+    for path in paths {
+        assert_eq!(path.extension().unwrap(), "svg");
+        let _ = std::fs::remove_file(format!("{}_temp.dot", path.to_str().unwrap()));
+        let _ = std::fs::remove_file(path);
+    }
+}
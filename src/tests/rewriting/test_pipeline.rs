@@ -0,0 +1,102 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::syntax::interaction::Interaction;
+use crate::equivalence_checker::check_equivalence::{check_equivalence_of_interactions, InteractionEquivalenceGlobalVerdict};
+use crate::error::HibouError;
+use crate::rewriting::canonize::canonize_interaction;
+use crate::rewriting::pipeline::{RewritingPipeline, RewritingPipelinePhase};
+use crate::rewriting::rules::high_level_hibou_rewrite_rules::HighLevelHibouRewriteRules;
+use crate::tests::canonize::util::parse;
+
+
+
+
+#[test]
+pub fn test_full_canonical_preset_is_canonize() {
+    let int = parse("alt(seq(l1 -- m1 -> l2, l2 -- m2 -> l3), seq(l1 -- m1 -> l2, l2 -- m3 -> l3))");
+    let canonized = canonize_interaction(&int, None, true, true);
+    assert_eq!(RewritingPipeline::full_canonical(true).rewrite(&int, None).unwrap(), canonized);
+    // the common prefix has been factorized
+    assert_eq!(canonized, parse("seq(l1 -- m1 -> l2, alt(l2 -- m2 -> l3, l2 -- m3 -> l3))"));
+}
+
+
+#[test]
+pub fn test_simplify_only_preset_does_not_factorize() {
+    let int = parse("alt(seq(l1 -- m1 -> l2, l2 -- m2 -> l3), seq(0, l1 -- m1 -> l2, l2 -- m3 -> l3))");
+    let simplified = RewritingPipeline::simplify_only(true).rewrite(&int, None).unwrap();
+    // the empty interaction has been removed but the alternative has not been factorized
+    assert!(matches!(simplified, Interaction::Alt(_,_)));
+    assert_ne!(simplified, canonize_interaction(&int, None, true, true));
+    assert_eq!(check_equivalence_of_interactions(&int, &simplified, None), InteractionEquivalenceGlobalVerdict::Equivalent);
+}
+
+
+#[test]
+pub fn test_custom_pipeline() {
+    let int = parse("alt(l1 -- m2 -> |, alt(0, l1 -- m1 -> |))");
+    // without any phase the interaction is left as is
+    assert_eq!(RewritingPipeline::new().rewrite(&int, None).unwrap(), int);
+    // a single phase with a subset of rules
+    let pipeline = RewritingPipeline::new()
+        .with_phase(
+            RewritingPipelinePhase::new(
+                vec![HighLevelHibouRewriteRules::AltAndCoregFlushRight, HighLevelHibouRewriteRules::ReorderSubInteractionsUnderAlt],
+                None,
+                None
+            )
+        );
+    let reordered = pipeline.rewrite(&int, None).unwrap();
+    assert_ne!(reordered, int);
+    assert!(!matches!(reordered, Interaction::Empty));
+    assert_eq!(check_equivalence_of_interactions(&int, &reordered, None), InteractionEquivalenceGlobalVerdict::Equivalent);
+    // with no rewrite steps allowed the interaction is left as is
+    assert_eq!(pipeline.max_rewrite_steps(Some(0)).rewrite(&int, None).unwrap(), int);
+}
+
+
+#[test]
+pub fn test_phase_with_bounded_applications() {
+    let int = parse("seq(0, l1 -- m1 -> l2, 0, l2 -- m2 -> l3, 0)");
+    let simplified = parse("seq(l1 -- m1 -> l2, l2 -- m2 -> l3)");
+    let get_phase = || RewritingPipelinePhase::new(vec![HighLevelHibouRewriteRules::EpsilonNeutral], None, None);
+    assert_eq!(RewritingPipeline::new().with_phase(get_phase()).rewrite(&int, None).unwrap(), simplified);
+    // a single application removes only one of the empty interactions
+    let bounded = RewritingPipeline::new()
+        .with_phase(get_phase().max_applications(Some(1)))
+        .rewrite(&int, None).unwrap();
+    assert_ne!(bounded, int);
+    assert_ne!(bounded, simplified);
+    assert_eq!(check_equivalence_of_interactions(&int, &bounded, None), InteractionEquivalenceGlobalVerdict::Equivalent);
+    // once its bound is reached the term of the phase is forwarded to the next phase
+    let mut first_phase = get_phase().max_applications(Some(1));
+    first_phase.next_phase_on_changed = Some(1);
+    first_phase.next_phase_on_unchanged = Some(1);
+    let pipeline = RewritingPipeline::new()
+        .with_phase(first_phase)
+        .with_phase(get_phase());
+    assert_eq!(pipeline.rewrite(&int, None).unwrap(), simplified);
+    // one removal in the first phase and the two others in the second one
+    assert_eq!(pipeline.rewrite_with_derivation(&int, None).unwrap().steps.len(), 3);
+    // the bound does not apply to each of several explored derivations
+    let bounded = RewritingPipeline::new().with_phase(get_phase().max_applications(Some(1)));
+    assert!(matches!(bounded.rewrite_to_all_irreducible_forms(&int, None), Err(HibouError::InvalidConfiguration(_))));
+    let bounded = bounded.keep_only_one(false);
+    assert!(matches!(bounded.rewrite(&int, None), Err(HibouError::InvalidConfiguration(_))));
+    assert!(matches!(bounded.rewrite_with_derivation(&int, None), Err(HibouError::InvalidConfiguration(_))));
+}
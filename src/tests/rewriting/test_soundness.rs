@@ -0,0 +1,114 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use maplit::btreeset;
+
+use crate::core::syntax::interaction::Interaction;
+#[cfg(feature = "generation")]
+use crate::generation::conf::{InteractionGenerationConfig, InteractionGenerationOperatorWeights};
+use crate::rewriting::rules::high_level_hibou_rewrite_rules::HighLevelHibouRewriteRules;
+use crate::rewriting::soundness::{apply_rule_once, bounded_trace_language, find_soundness_violation, shrink_counterexample};
+#[cfg(feature = "generation")]
+use crate::rewriting::soundness::RewriteSoundnessHarness;
+use crate::tests::canonize::util::{get_gen_ctx, parse};
+use crate::tests::semantics::util::{em, rc};
+
+
+
+
+#[test]
+pub fn test_bounded_trace_language() {
+    let int = parse("seq(l1 -- m1 -> l2, loopS(l3 -- m3 -> |))");
+    assert_eq!(
+        bounded_trace_language(&int, 3),
+        btreeset!{
+            vec![em(0,0),rc(1,0)],
+            vec![em(0,0),rc(1,0),em(2,2)],
+            vec![em(0,0),em(2,2),rc(1,0)],
+            vec![em(2,2),em(0,0),rc(1,0)]
+        }
+    );
+    assert_eq!(bounded_trace_language(&Interaction::Empty, 3), btreeset!{vec![]});
+}
+
+
+#[cfg(feature = "generation")]
+#[test]
+pub fn test_all_rules_preserve_bounded_trace_languages() {
+    let ctx = get_gen_ctx();
+    let harness = RewriteSoundnessHarness::new(
+        4,
+        150,
+        0,
+        InteractionGenerationConfig::new(3, 0.2, 0.9, InteractionGenerationOperatorWeights::default()).unwrap()
+    );
+    let violations = harness.check_rules(&ctx, &HighLevelHibouRewriteRules::get_all_rules());
+    assert!(
+        violations.is_empty(),
+        "{}",
+        violations.iter().map(|violation| violation.as_text(&ctx)).collect::<Vec<String>>().join("\n")
+    );
+}
+
+
+#[test]
+pub fn test_sequencing_compatibility_left_does_not_cross_the_previous_operand() {
+    // l1!m2 cannot be moved before l1!m1
+    let int = parse("strict(seq(l1 -- m1 -> l2, l1 -- m2 -> |), l1 -- m3 -> |)");
+    assert!(apply_rule_once(HighLevelHibouRewriteRules::SequencingCompatibilityLeft, &int).is_empty());
+    assert_eq!(find_soundness_violation(HighLevelHibouRewriteRules::SequencingCompatibilityLeft, &int, 4), None);
+}
+
+
+#[test]
+pub fn test_kleene_desequencing_keeps_merged_loop_in_place() {
+    let int = parse("strict(l2 -- m2 -> |, loopS(l1 -- m1 -> |), loopS(l1 -- m1 -> |), l1 -- m2 -> |)");
+    assert_eq!(apply_rule_once(HighLevelHibouRewriteRules::KleeneDesequencing, &int).len(), 1);
+    assert_eq!(find_soundness_violation(HighLevelHibouRewriteRules::KleeneDesequencing, &int, 4), None);
+    // incomparable loop kinds are simply not merged
+    let int = parse("seq(loopC(l1)(l1 -- m1 -> |), loopC(l2)(l1 -- m1 -> |))");
+    assert_eq!(find_soundness_violation(HighLevelHibouRewriteRules::KleeneDesequencing, &int, 4), None);
+}
+
+
+#[test]
+pub fn test_counterexample_is_minimized() {
+    // an unsound transformation which forgets the right operand of the strict sequencing at the root
+    let forget_right_of_strict = |int : &Interaction| -> Option<Interaction> {
+        match int {
+            Interaction::Strict(i1,i2) if bounded_trace_language(int, 4) != bounded_trace_language(i1, 4) => {
+                Some(int.clone())
+            },
+            _ => {
+                None
+            }
+        }
+    };
+    let int = parse("strict(alt(l1 -- m1 -> l2, seq(l2 -- m2 -> |, l3 -- m3 -> |)), strict(l3 -- m3 -> l1, loopS(l1 -- m1 -> |)))");
+    let counterexample = forget_right_of_strict(&int).unwrap();
+    let minimized = shrink_counterexample(counterexample, |found| found, forget_right_of_strict);
+    // the smallest such interaction forgets a single action
+    match &minimized {
+        Interaction::Strict(i1,i2) => {
+            assert_eq!(**i1, Interaction::Empty);
+            assert!(matches!(**i2, Interaction::Emission(_) | Interaction::Reception(_)));
+        },
+        _ => {
+            panic!("unexpected minimized counterexample {:?}", minimized);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use crate::core::semantics::accepted_traces::accepted_traces;
+use crate::core::semantics::trace_action::TraceAction;
+use crate::core::syntax::interaction::Interaction;
+#[cfg(feature = "generation")]
+use crate::generation::conf::InteractionGenerationConfig;
+#[cfg(feature = "generation")]
+use crate::generation::generate::generate_interaction_from_seed;
+use crate::rewriting::canonize::canonize_interaction;
+use crate::tests::canonize::util::{get_gen_ctx, parse};
+
+use super::util::{em, naive_accepts, rc};
+
+
+
+
+#[test]
+pub fn test_accepted_traces_by_increasing_length() {
+    let int = parse("seq(l1 -- m1 -> l2, loopS(l3 -- m3 -> |))");
+    let traces : Vec<Vec<TraceAction>> = accepted_traces(&int, 3, None).collect();
+    assert_eq!(
+        traces,
+        vec![
+            vec![em(0,0),rc(1,0)],
+            vec![em(0,0),rc(1,0),em(2,2)],
+            vec![em(0,0),em(2,2),rc(1,0)],
+            vec![em(2,2),em(0,0),rc(1,0)]
+        ]
+    );
+    assert_eq!(accepted_traces(&Interaction::Empty, 3, None).collect::<Vec<Vec<TraceAction>>>(), vec![vec![]]);
+}
+
+
+#[test]
+pub fn test_accepted_traces_are_deduplicated() {
+    // both alternatives and both orders of the concurrent emissions yield the same traces
+    let int = parse("alt(par(l1 -- m1 -> |, l1 -- m1 -> |), seq(l1 -- m1 -> |, l1 -- m1 -> |))");
+    let traces : Vec<Vec<TraceAction>> = accepted_traces(&int, 4, None).collect();
+    assert_eq!(traces, vec![vec![em(0,0),em(0,0)]]);
+}
+
+
+#[test]
+pub fn test_accepted_traces_bounded_loop_depth() {
+    let int = parse("loopS(l1 -- m1 -> |)");
+    let traces : Vec<Vec<TraceAction>> = accepted_traces(&int, 10, Some(2)).collect();
+    assert_eq!(traces, vec![vec![], vec![em(0,0)], vec![em(0,0),em(0,0)]]);
+    // without a loop depth bound, only the length bounds the enumeration
+    assert_eq!(accepted_traces(&int, 10, None).count(), 11);
+}
+
+
+#[test]
+pub fn test_accepted_traces_are_enumerated_lazily() {
+    // there are 2^30 accepted traces of length 30 but only the shortest ones are explored
+    let int = parse("loopS(alt(l1 -- m1 -> |, l2 -- m2 -> |))");
+    let traces : Vec<Vec<TraceAction>> = accepted_traces(&int, 30, None).take(3).collect();
+    assert_eq!(traces, vec![vec![], vec![em(0,0)], vec![em(1,1)]]);
+}
+
+
+#[cfg(feature = "generation")]
+#[test]
+pub fn test_accepted_traces_are_accepted() {
+    let ctx = get_gen_ctx();
+    let config = InteractionGenerationConfig::default();
+    for seed in 0..30 {
+        let int = generate_interaction_from_seed(&ctx, &config, seed);
+        let traces : Vec<Vec<TraceAction>> = accepted_traces(&int, 4, None).collect();
+        let distinct : BTreeSet<&Vec<TraceAction>> = traces.iter().collect();
+        assert_eq!(distinct.len(), traces.len());
+        for (trace_id,trace) in traces.iter().enumerate() {
+            assert!(naive_accepts(&int, trace));
+            if let Some(previous) = trace_id.checked_sub(1).map(|id| &traces[id]) {
+                assert!(previous.len() <= trace.len());
+            }
+        }
+    }
+}
+
+
+#[cfg(feature = "generation")]
+#[test]
+pub fn test_canonization_preserves_accepted_traces() {
+    let ctx = get_gen_ctx();
+    let config = InteractionGenerationConfig::default();
+    for seed in 0..10 {
+        let int = generate_interaction_from_seed(&ctx, &config, seed);
+        let canonized = canonize_interaction(&int, None, true, true);
+        assert_eq!(
+            accepted_traces(&int, 4, None).collect::<Vec<Vec<TraceAction>>>(),
+            accepted_traces(&canonized, 4, None).collect::<Vec<Vec<TraceAction>>>()
+        );
+    }
+}
@@ -0,0 +1,125 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use maplit::btreeset;
+
+use crate::core::semantics::execute::execute_interaction;
+use crate::core::semantics::frontier::global_frontier;
+use crate::core::syntax::interaction::Interaction;
+use crate::core::syntax::lang_traits::eliminate_lf::eliminable::LifelineEliminable;
+use crate::core::syntax::lang_traits::prune::prunable::LifelinePrunable;
+use crate::seqdiag_lib_interface::io::parse_interaction_from_text;
+use crate::tests::canonize::util::{get_gen_ctx, parse};
+
+use super::util::{em, naive_accepts, rc};
+
+
+fn make_and(left_text : &str, right_text : &str) -> Interaction {
+    Interaction::And(Box::new(parse(left_text)), Box::new(parse(right_text)))
+}
+
+
+
+#[test]
+pub fn test_and_frontier_is_intersection() {
+    let int = make_and(
+        "alt(l1 -- m1 -> |, l1 -- m2 -> |)",
+        "alt(l1 -- m2 -> |, l1 -- m3 -> |)"
+    );
+    let frontier = global_frontier(&int, false);
+    assert_eq!(frontier.len(), 1);
+    let frt_elt = frontier.first().unwrap();
+    assert_eq!(frt_elt.target_actions, btreeset!{em(0,1)});
+    // ***
+    let follow_up = execute_interaction(&int, &frt_elt.position, &frt_elt.target_lf_ids, false).interaction;
+    assert_eq!(follow_up, Interaction::Empty);
+    // ***
+    assert!(naive_accepts(&int, &[em(0,1)]));
+    assert!(!naive_accepts(&int, &[em(0,0)]));
+    assert!(!naive_accepts(&int, &[em(0,2)]));
+    assert!(!naive_accepts(&int, &[]));
+}
+
+
+#[test]
+pub fn test_and_with_loop() {
+    // the intersection of the repetition of m1 with exactly two m1
+    let int = make_and(
+        "loopS(l1 -- m1 -> l2)",
+        "seq(l1 -- m1 -> l2, l1 -- m1 -> l2)"
+    );
+    assert!(!int.express_empty());
+    let twice = [em(0,0),rc(1,0),em(0,0),rc(1,0)];
+    assert!(naive_accepts(&int, &twice));
+    assert!(!naive_accepts(&int, &twice[0..2]));
+    assert!(!naive_accepts(&int, &[em(0,0),rc(1,0),em(0,0),rc(1,0),em(0,0),rc(1,0)]));
+    // seq would allow the second emission to occur before the first reception but loopS does not
+    assert!(!naive_accepts(&int, &[em(0,0),em(0,0),rc(1,0),rc(1,0)]));
+}
+
+
+#[test]
+pub fn test_and_under_weak_sequencing() {
+    // the "and" is on the left of a seq and does not involve l3
+    // hence the emission by l3 on the right can occur first
+    let gen_ctx = get_gen_ctx();
+    let conj = make_and(
+        "seq(l1 -- m1 -> l2, loopW(l2 -- m2 -> |))",
+        "loopW(alt(l1 -- m1 -> l2, l2 -- m2 -> |))"
+    );
+    let int = Interaction::CoReg(
+        vec![],
+        Box::new(conj),
+        Box::new(parse_interaction_from_text("l3 -- m3 -> |", &gen_ctx).unwrap())
+    );
+    assert!(naive_accepts(&int, &[em(2,2),em(0,0),rc(1,0)]));
+    assert!(naive_accepts(&int, &[em(0,0),rc(1,0),em(1,1),em(2,2)]));
+    assert!(!naive_accepts(&int, &[em(1,1),em(2,2)]));
+}
+
+
+#[test]
+pub fn test_and_prune_and_eliminate() {
+    let int = make_and(
+        "seq(loopW(l1 -- m1 -> |), l2 -- m2 -> |)",
+        "seq(loopW(l1 -- m1 -> |), loopW(l2 -- m2 -> |))"
+    );
+    let gen_ctx = get_gen_ctx();
+    // ***
+    let pruned = int.prune(&btreeset!{0});
+    let expected_pruned = make_and(
+        "l2 -- m2 -> |",
+        "loopW(l2 -- m2 -> |)"
+    );
+    assert_eq!(pruned, expected_pruned);
+    // ***
+    let eliminated = int.eliminate_lifelines(&btreeset!{1});
+    let expected_eliminated = make_and(
+        "loopW(l1 -- m1 -> |)",
+        "loopW(l1 -- m1 -> |)"
+    );
+    assert_eq!(eliminated, expected_eliminated);
+    // ***
+    let reversed = int.reverse_interaction();
+    assert_eq!(
+        reversed,
+        Interaction::And(
+            Box::new(parse_interaction_from_text("seq(loopW(l2 -- m2 -> |), loopW(l1 -- m1 -> |))", &gen_ctx).unwrap()),
+            Box::new(parse_interaction_from_text("seq(l2 -- m2 -> |, loopW(l1 -- m1 -> |))", &gen_ctx).unwrap())
+        )
+    );
+}
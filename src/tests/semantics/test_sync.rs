@@ -0,0 +1,126 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use maplit::btreeset;
+
+use crate::core::semantics::execute::execute_interaction;
+use crate::core::semantics::frontier::global_frontier;
+use crate::core::semantics::trace_action::{TraceAction, TraceActionKind};
+use crate::core::syntax::interaction::Interaction;
+use crate::core::syntax::lang_traits::eliminate_lf::eliminable::LifelineEliminable;
+use crate::seqdiag_lib_interface::io::{draw_interaction_on_file, parse_interaction_from_text, read_interaction_from_text_on_file, write_interaction_as_text_on_file, InteractionDrawingKind};
+use crate::tests::canonize::util::get_gen_ctx;
+
+use super::util::{em, naive_accepts};
+
+
+
+
+#[test]
+pub fn test_sync_blocks_until_both_sides_are_ready() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text(
+        "sync(l1!m1)(l1 -- m1 -> |, strict(l2 -- m2 -> |, l1 -- m1 -> |))",
+        &gen_ctx
+    ).unwrap();
+    // ***
+    let frontier = global_frontier(&int, false);
+    assert_eq!(frontier.len(), 1);
+    assert_eq!(frontier.first().unwrap().target_actions, btreeset!{em(1,1)});
+    // ***
+    assert!(naive_accepts(&int, &[em(1,1),em(0,0)]));
+    assert!(!naive_accepts(&int, &[em(0,0),em(1,1)]));
+    assert!(!naive_accepts(&int, &[em(1,1),em(0,0),em(0,0)]));
+    assert!(!naive_accepts(&int, &[em(1,1)]));
+}
+
+
+#[test]
+pub fn test_sync_falls_back_to_par() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text(
+        "sync(l1!m1)(strict(l1 -- m1 -> |, l2 -- m2 -> |), strict(l1 -- m1 -> |, l3 -- m3 -> |))",
+        &gen_ctx
+    ).unwrap();
+    let frontier = global_frontier(&int, false);
+    assert_eq!(frontier.len(), 1);
+    let frt_elt = frontier.first().unwrap();
+    let follow_up = execute_interaction(&int, &frt_elt.position, &frt_elt.target_lf_ids, false).interaction;
+    // no synchronised action remains, hence the operands are simply interleaved
+    let expected = parse_interaction_from_text(
+        "coreg(l2,l3)(l2 -- m2 -> |, l3 -- m3 -> |)",
+        &gen_ctx
+    ).unwrap();
+    assert_eq!(follow_up, expected);
+    // ***
+    assert!(naive_accepts(&int, &[em(0,0),em(2,2),em(1,1)]));
+    assert!(!naive_accepts(&int, &[em(0,0),em(0,0),em(1,1),em(2,2)]));
+}
+
+
+#[test]
+pub fn test_sync_eliminate_synchronised_lifeline() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text(
+        "sync(l1!m1,l2!m2)(l1 -- m1 -> |, seq(l2 -- m2 -> |, l3 -- m3 -> |))",
+        &gen_ctx
+    ).unwrap();
+    let eliminated = int.eliminate_lifelines(&btreeset!{0});
+    let expected = parse_interaction_from_text(
+        "sync(l2!m2)(0, seq(l2 -- m2 -> |, l3 -- m3 -> |))",
+        &gen_ctx
+    ).unwrap();
+    assert_eq!(eliminated, expected);
+    // l2!m2 is synchronised but can never occur on the left hence l3!m3 cannot occur
+    assert!(!naive_accepts(&eliminated, &[em(1,1),em(2,2)]));
+    assert!(eliminated.eliminate_lifelines(&btreeset!{1}) == parse_interaction_from_text("l3 -- m3 -> |", &gen_ctx).unwrap());
+}
+
+
+#[test]
+pub fn test_sync_print_parse_and_draw() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text(
+        "seq(sync(l1!m1,l2?m1)(l1 -- m1 -> l2, alt(l1 -- m1 -> l2, l3 -- m3 -> |)), l2 -- m2 -> l3)",
+        &gen_ctx
+    ).unwrap();
+    match &int {
+        Interaction::CoReg(_, i1, _) => {
+            match &**i1 {
+                Interaction::Sync(sync_acts, _, _) => {
+                    assert_eq!(sync_acts, &vec![em(0,0),TraceAction::new(1, TraceActionKind::Reception, 0)]);
+                },
+                _ => {
+                    panic!("expected a sync operator");
+                }
+            }
+        },
+        _ => {
+            panic!("expected a seq operator");
+        }
+    }
+    // ***
+    let temp_dir = std::env::temp_dir();
+    let txt_path = temp_dir.join("hibou_test_sync.hif");
+    write_interaction_as_text_on_file(&txt_path, &gen_ctx, &int, true);
+    let reparsed = read_interaction_from_text_on_file(&txt_path, &gen_ctx).unwrap();
+    assert_eq!(int, reparsed);
+    // ***
+    let png_path = temp_dir.join("hibou_test_sync.png");
+    draw_interaction_on_file(&png_path, &gen_ctx, &int, &InteractionDrawingKind::AsSequenceDiagram);
+    assert!(png_path.exists());
+}
@@ -0,0 +1,113 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::semantics::position::Position;
+use crate::core::semantics::trace_action::{TraceAction, TraceActionKind};
+use crate::core::syntax::action::{EmissionAction, ReceptionAction};
+use crate::core::syntax::interaction::{Interaction, LoopKind};
+use crate::core::syntax::validation::{has_errors, validate, Diagnostic, DiagnosticKind, DiagnosticSeverity};
+use crate::error::HibouError;
+use crate::seqdiag_lib_interface::io::{parse_interaction_from_text, try_draw_interaction_on_file, InteractionDrawingKind};
+use crate::tests::canonize::util::get_gen_ctx;
+
+
+
+#[test]
+pub fn test_validate_parsed_interaction_is_well_formed() {
+    let gen_ctx = get_gen_ctx();
+    let int = parse_interaction_from_text(
+        "seq(l1 -- m1 -> l2, coreg(l2,l3)(l2 -- m2 -> |, l3 -- m3 -> |), loopC(l1,l3)(l3 -- m2 -> l1))",
+        &gen_ctx
+    ).unwrap();
+    assert_eq!(validate(&int, &gen_ctx), vec![]);
+}
+
+
+#[test]
+pub fn test_validate_reports_unknown_elements() {
+    let gen_ctx = get_gen_ctx();
+    // l1 -- m4 -> l4 with m4 and l4 undeclared, the emission also targeting an undeclared gate
+    let int = Interaction::Strict(
+        Box::new(Interaction::Emission(EmissionAction::new(0, 3, vec![0]))),
+        Box::new(Interaction::Reception(ReceptionAction::new(None, 0, 3)))
+    );
+    let left = Position::Left(Box::new(Position::Epsilon));
+    let right = Position::Right(Box::new(Position::Epsilon));
+    let diagnostics = validate(&int, &gen_ctx);
+    assert_eq!(diagnostics, vec![
+        Diagnostic::new(DiagnosticSeverity::Error, DiagnosticKind::UnknownMessage(3), left.clone()),
+        Diagnostic::new(DiagnosticSeverity::Error, DiagnosticKind::UnknownGate(0), left),
+        Diagnostic::new(DiagnosticSeverity::Error, DiagnosticKind::UnknownLifeline(3), right)
+    ]);
+    assert!(has_errors(&diagnostics));
+    // the fallible drawing refuses to draw it
+    let png_path = std::env::temp_dir().join("hibou_test_validation.png");
+    assert_eq!(
+        try_draw_interaction_on_file(&png_path, &gen_ctx, &int, &InteractionDrawingKind::AsSequenceDiagram),
+        Err(HibouError::UnknownMessage(3))
+    );
+}
+
+
+#[test]
+pub fn test_validate_reports_non_normalized_coregions_and_sync() {
+    let gen_ctx = get_gen_ctx();
+    let em1 = Interaction::Emission(EmissionAction::new(0, 0, vec![]));
+    let em2 = Interaction::Emission(EmissionAction::new(1, 1, vec![]));
+    let int = Interaction::CoReg(
+        vec![2, 0, 2],
+        Box::new(Interaction::Loop(LoopKind::Coreg(vec![1, 0]), Box::new(em1.clone()))),
+        Box::new(Interaction::Sync(
+            vec![TraceAction::new(1, TraceActionKind::Emission, 1), TraceAction::new(1, TraceActionKind::Emission, 1)],
+            Box::new(em2.clone()),
+            Box::new(em2)
+        ))
+    );
+    let diagnostics = validate(&int, &gen_ctx);
+    let kinds : Vec<DiagnosticKind> = diagnostics.iter().map(|d| d.kind.clone()).collect();
+    assert_eq!(kinds, vec![
+        DiagnosticKind::DuplicateLifelineInCoregion(2),
+        DiagnosticKind::UnsortedCoregion(vec![2, 0, 2]),
+        DiagnosticKind::UnsortedCoregion(vec![1, 0]),
+        DiagnosticKind::DuplicateSynchronisationAction(TraceAction::new(1, TraceActionKind::Emission, 1))
+    ]);
+    assert!(diagnostics.iter().all(|d| d.severity == DiagnosticSeverity::Warning));
+    assert!(!has_errors(&diagnostics));
+    assert_eq!(diagnostics[2].position, Position::Left(Box::new(Position::Epsilon)));
+}
+
+
+#[test]
+pub fn test_validate_reports_dead_actions_under_and() {
+    let gen_ctx = get_gen_ctx();
+    let i1 = parse_interaction_from_text("seq(l1 -- m1 -> |, l2 -- m2 -> |)", &gen_ctx).unwrap();
+    let i2 = parse_interaction_from_text("alt(l1 -- m1 -> |, l3 -- m3 -> |)", &gen_ctx).unwrap();
+    let int = Interaction::And(Box::new(i1), Box::new(i2));
+    let diagnostics = validate(&int, &gen_ctx);
+    assert_eq!(diagnostics, vec![
+        Diagnostic::new(
+            DiagnosticSeverity::Warning, 
+            DiagnosticKind::DeadActionUnderAnd(TraceAction::new(1, TraceActionKind::Emission, 1)), 
+            Position::Epsilon
+        ),
+        Diagnostic::new(
+            DiagnosticSeverity::Warning, 
+            DiagnosticKind::DeadActionUnderAnd(TraceAction::new(2, TraceActionKind::Emission, 2)), 
+            Position::Epsilon
+        )
+    ]);
+}
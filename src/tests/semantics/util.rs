@@ -0,0 +1,60 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::semantics::execute::execute_interaction;
+use crate::core::semantics::frontier::global_frontier;
+use crate::core::semantics::trace_action::{TraceAction, TraceActionKind};
+use crate::core::syntax::interaction::Interaction;
+
+
+
+/** 
+ * Naive exhaustive check that a global trace is accepted by an interaction.
+ * **/
+pub fn naive_accepts(int : &Interaction, trace : &[TraceAction]) -> bool {
+    match trace.split_first() {
+        None => {
+            int.express_empty()
+        },
+        Some((head,rest)) => {
+            for frt_elt in global_frontier(int, false) {
+                if frt_elt.target_actions.contains(head) {
+                    let follow_up = execute_interaction(
+                        int,
+                        &frt_elt.position,
+                        &frt_elt.target_lf_ids,
+                        false
+                    ).interaction;
+                    if naive_accepts(&follow_up, rest) {
+                        return true;
+                    }
+                }
+            }
+            false
+        }
+    }
+}
+
+
+
+pub fn em(lf_id : usize, ms_id : usize) -> TraceAction {
+    TraceAction::new(lf_id, TraceActionKind::Emission, ms_id)
+}
+
+pub fn rc(lf_id : usize, ms_id : usize) -> TraceAction {
+    TraceAction::new(lf_id, TraceActionKind::Reception, ms_id)
+}